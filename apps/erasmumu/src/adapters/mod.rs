@@ -1 +1,6 @@
+pub mod mongo_company;
+pub mod mongo_domain;
 pub mod mongo_offer;
+pub mod mongo_offer_revision;
+pub mod static_exchange_rates;
+pub mod system_clock;
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use mongodb::{Client, Collection};
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::company::Company;
+use zukmove_core::domain::ports::{CompanyRepository, DomainError};
+
+use super::mongo_offer::uuid_to_bson;
+
+pub struct MongoCompanyRepository {
+    collection: Collection<Company>,
+}
+
+impl MongoCompanyRepository {
+    pub fn new(client: &Client, database: &str) -> Self {
+        let db = client.database(database);
+        let collection = db.collection::<Company>("companies");
+        Self { collection }
+    }
+}
+
+#[async_trait]
+impl CompanyRepository for MongoCompanyRepository {
+    async fn save(&self, company: &Company) -> Result<Company, DomainError> {
+        self.collection
+            .insert_one(company)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        Ok(company.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Company, DomainError> {
+        self.collection
+            .find_one(doc! { "id": uuid_to_bson(id)? })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Company with id {} not found", id)))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Company>, DomainError> {
+        let mut cursor = self
+            .collection
+            .find(doc! {})
+            .sort(doc! { "name": 1 })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let mut companies = Vec::new();
+        while cursor
+            .advance()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        {
+            let company = cursor
+                .deserialize_current()
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            companies.push(company);
+        }
+        Ok(companies)
+    }
+
+    async fn update(&self, company: &Company) -> Result<Company, DomainError> {
+        let result = self
+            .collection
+            .replace_one(doc! { "id": uuid_to_bson(company.id)? }, company)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.matched_count == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Company with id {} not found",
+                company.id
+            )));
+        }
+        Ok(company.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = self
+            .collection
+            .delete_one(doc! { "id": uuid_to_bson(id)? })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.deleted_count == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Company with id {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+}
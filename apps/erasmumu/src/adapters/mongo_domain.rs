@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use mongodb::{Client, Collection};
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::domain::Domain;
+use zukmove_core::domain::ports::{DomainError, DomainRepository};
+
+use super::mongo_offer::uuid_to_bson;
+
+pub struct MongoDomainRepository {
+    collection: Collection<Domain>,
+}
+
+impl MongoDomainRepository {
+    pub fn new(client: &Client, database: &str) -> Self {
+        let db = client.database(database);
+        let collection = db.collection::<Domain>("domains");
+        Self { collection }
+    }
+}
+
+#[async_trait]
+impl DomainRepository for MongoDomainRepository {
+    async fn save(&self, domain: &Domain) -> Result<Domain, DomainError> {
+        self.collection
+            .insert_one(domain)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        Ok(domain.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Domain, DomainError> {
+        self.collection
+            .find_one(doc! { "id": uuid_to_bson(id)? })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Domain with id {} not found", id)))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Domain>, DomainError> {
+        let mut cursor = self
+            .collection
+            .find(doc! {})
+            .sort(doc! { "name": 1 })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let mut domains = Vec::new();
+        while cursor
+            .advance()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        {
+            let domain = cursor
+                .deserialize_current()
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            domains.push(domain);
+        }
+        Ok(domains)
+    }
+
+    async fn update(&self, domain: &Domain) -> Result<Domain, DomainError> {
+        let result = self
+            .collection
+            .replace_one(doc! { "id": uuid_to_bson(domain.id)? }, domain)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.matched_count == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Domain with id {} not found",
+                domain.id
+            )));
+        }
+        Ok(domain.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = self
+            .collection
+            .delete_one(doc! { "id": uuid_to_bson(id)? })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        if result.deleted_count == 0 {
+            return Err(DomainError::NotFound(format!(
+                "Domain with id {} not found",
+                id
+            )));
+        }
+        Ok(())
+    }
+}
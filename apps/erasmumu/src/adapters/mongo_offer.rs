@@ -1,63 +1,75 @@
 use async_trait::async_trait;
-use mongodb::bson::{Bson, doc, to_bson};
-use mongodb::{Client, Collection};
+use chrono::{DateTime, NaiveDate, Utc};
+use mongodb::bson::{Bson, Document, doc};
+use mongodb::options::{IndexOptions, ReturnDocument};
+use mongodb::{Client, Collection, IndexModel};
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::offer::Offer;
+use zukmove_core::domain::entities::money::{ExchangeRates, minor_units};
+use zukmove_core::domain::entities::offer::{Offer, OfferFilter, OfferVisibility};
+use zukmove_core::domain::normalize::canonical_key;
+use zukmove_core::domain::pagination::{Page, PageRequest, SortDirection, SortKey};
 use zukmove_core::domain::ports::{DomainError, OfferRepository};
 
 pub struct MongoOfferRepository {
     collection: Collection<Offer>,
+    /// Same collection, used to write offers together with their canonical keys.
+    documents: Collection<Document>,
 }
 
 impl MongoOfferRepository {
     pub fn new(client: &Client, database: &str) -> Self {
         let db = client.database(database);
         let collection = db.collection::<Offer>("offers");
-        Self { collection }
+        let documents = collection.clone_with_type::<Document>();
+        Self {
+            collection,
+            documents,
+        }
     }
-}
 
-#[async_trait]
-impl OfferRepository for MongoOfferRepository {
-    async fn save(&self, offer: &Offer) -> Result<Offer, DomainError> {
-        self.collection
-            .insert_one(offer)
+    /// Stores the canonical city/domain keys on offers written before they existed.
+    pub async fn backfill_canonical_keys(&self) -> Result<(), DomainError> {
+        let mut cursor = self
+            .collection
+            .find(doc! { "$or": [
+                { "city_key": { "$exists": false } },
+                { "domain_key": { "$exists": false } },
+            ] })
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
-        Ok(offer.clone())
-    }
 
-    async fn find_by_id(&self, id: Uuid) -> Result<Offer, DomainError> {
-        let id_bson = uuid_to_bson(id)?;
-        let filter = doc! { "id": id_bson };
-        let offer = self
-            .collection
-            .find_one(filter)
+        while cursor
+            .advance()
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
-            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
-
-        // Business rule: do not return unavailable offers
-        if !offer.available {
-            return Err(DomainError::NotFound(format!(
-                "Offer with id {} is not available",
-                id
-            )));
+        {
+            let offer = cursor
+                .deserialize_current()
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            self.collection
+                .update_one(
+                    doc! { "id": uuid_to_bson(offer.id)? },
+                    doc! { "$set": {
+                        "city_key": canonical_key(&offer.city),
+                        "domain_key": canonical_key(&offer.domain),
+                    } },
+                )
+                .await
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
         }
-
-        Ok(offer)
+        Ok(())
     }
 
-    async fn find_by_domain(&self, domain: &str) -> Result<Vec<Offer>, DomainError> {
-        let filter = doc! { "domain": domain, "available": true };
+    /// Rewrites legacy numeric salaries as `Money` and recomputes the base salary of every
+    /// offer whose value changed with the current rates.
+    pub async fn normalize_salaries(&self, rates: &ExchangeRates) -> Result<(), DomainError> {
         let mut cursor = self
             .collection
-            .find(filter)
+            .find(doc! {})
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
-        let mut offers = Vec::new();
         while cursor
             .advance()
             .await
@@ -66,16 +78,98 @@ impl OfferRepository for MongoOfferRepository {
             let offer = cursor
                 .deserialize_current()
                 .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
-            offers.push(offer);
+            let base_salary = match rates.to_base(&offer.salary) {
+                Ok(base) => Some(base),
+                Err(e) => {
+                    log::warn!("Offer {} keeps no base salary: {}", offer.id, e);
+                    None
+                }
+            };
+            if offer.base_salary == base_salary && cursor.current().get_document("salary").is_ok() {
+                continue;
+            }
+            self.collection
+                .update_one(
+                    doc! { "id": uuid_to_bson(offer.id)? },
+                    doc! { "$set": {
+                        "salary": to_bson(&offer.salary)?,
+                        "base_salary": to_bson(&base_salary)?,
+                    } },
+                )
+                .await
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
         }
-        Ok(offers)
+        Ok(())
+    }
+
+    /// Creates the text index used by `search_text` and the canonical key indexes. Idempotent.
+    pub async fn ensure_indexes(&self) -> Result<(), DomainError> {
+        let text_index = IndexModel::builder()
+            .keys(doc! { "title": "text" })
+            .options(
+                IndexOptions::builder()
+                    .name("offers_text".to_string())
+                    .default_language("none".to_string())
+                    .build(),
+            )
+            .build();
+        let keys_index = IndexModel::builder()
+            .keys(doc! { "domain_key": 1, "city_key": 1 })
+            .build();
+        let city_index = IndexModel::builder().keys(doc! { "city_key": 1 }).build();
+        self.collection
+            .create_indexes([text_index, keys_index, city_index])
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        Ok(())
     }
 
-    async fn find_by_city(&self, city: &str) -> Result<Vec<Offer>, DomainError> {
-        let filter = doc! { "city": city, "available": true };
+    /// Keyset pagination: resumes strictly after the cursor in (sort field, id) order
+    /// and fetches one extra document to know whether a next page exists.
+    async fn find_page(
+        &self,
+        mut filter: Document,
+        page: &PageRequest,
+    ) -> Result<Page<Offer>, DomainError> {
+        // Salaries are compared through their amount in the base currency
+        let field = match page.sort.field.as_str() {
+            "salary" => "base_salary.amount",
+            field => field,
+        };
+        let (op, order) = match page.sort.direction {
+            SortDirection::Asc => ("$gt", 1),
+            SortDirection::Desc => ("$lt", -1),
+        };
+
+        if let Some(ref cursor) = page.cursor {
+            let id = uuid_to_bson(cursor.id)?;
+            let key = match cursor.key {
+                SortKey::Null => Bson::Null,
+                SortKey::Number(n) => Bson::Double(n),
+                SortKey::Text(ref t) => Bson::String(t.clone()),
+            };
+            // Missing values (an offer without a base salary) sort before any other value
+            let mut after = vec![doc! { field: key.clone(), "id": { op: id } }];
+            match (key, page.sort.direction) {
+                (Bson::Null, SortDirection::Asc) => {
+                    after.push(doc! { field: { "$ne": Bson::Null } });
+                }
+                (Bson::Null, SortDirection::Desc) => {}
+                (key, direction) => {
+                    after.push(doc! { field: { op: key } });
+                    if direction == SortDirection::Desc {
+                        after.push(doc! { field: Bson::Null });
+                    }
+                }
+            }
+            filter.insert("$or", after);
+        }
+
         let mut cursor = self
             .collection
             .find(filter)
+            .sort(doc! { field: order, "id": order })
+            .limit(i64::from(page.limit) + 1)
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
@@ -90,14 +184,95 @@ impl OfferRepository for MongoOfferRepository {
                 .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
             offers.push(offer);
         }
-        Ok(offers)
+        Ok(Page::from_overfetch(offers, page))
+    }
+
+    /// Distinguishes a missing offer from a conditional update whose condition did not match.
+    async fn conditional_update_failure(&self, id: Uuid, mismatch: DomainError) -> DomainError {
+        let id_bson = match uuid_to_bson(id) {
+            Ok(b) => b,
+            Err(e) => return e,
+        };
+        match self.collection.find_one(doc! { "id": id_bson }).await {
+            Ok(Some(_)) => mismatch,
+            Ok(None) => DomainError::NotFound(format!("Offer with id {} not found", id)),
+            Err(e) => DomainError::InfrastructureError(e.to_string()),
+        }
+    }
+}
+
+#[async_trait]
+impl OfferRepository for MongoOfferRepository {
+    async fn save(&self, offer: &Offer) -> Result<Offer, DomainError> {
+        self.documents
+            .insert_one(offer_to_document(offer)?)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        Ok(offer.clone())
+    }
+
+    async fn save_many(&self, offers: &[Offer]) -> Result<(), DomainError> {
+        if offers.is_empty() {
+            return Ok(());
+        }
+        let documents = offers
+            .iter()
+            .map(offer_to_document)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.documents
+            .insert_many(documents)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        Ok(())
     }
 
-    async fn find_all(&self) -> Result<Vec<Offer>, DomainError> {
-        let filter = doc! { "available": true };
+    async fn find_by_id(&self, id: Uuid) -> Result<Offer, DomainError> {
+        let id_bson = uuid_to_bson(id)?;
+        let filter = doc! { "id": id_bson };
+        let offer = self
+            .collection
+            .find_one(filter)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
+
+        // Business rule: do not return unavailable or archived offers
+        if !offer.available || offer.is_archived() {
+            return Err(DomainError::NotFound(format!(
+                "Offer with id {} is not available",
+                id
+            )));
+        }
+
+        Ok(offer)
+    }
+
+    async fn find_by_id_including_unavailable(&self, id: Uuid) -> Result<Offer, DomainError> {
+        self.collection
+            .find_one(doc! { "id": uuid_to_bson(id)? })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))
+    }
+
+    async fn search(
+        &self,
+        filter: &OfferFilter,
+        visibility: OfferVisibility,
+        page: &PageRequest,
+    ) -> Result<Page<Offer>, DomainError> {
+        self.find_page(offer_filter_to_document(filter, visibility)?, page)
+            .await
+    }
+
+    async fn search_text(&self, query: &str, limit: u32) -> Result<Vec<Offer>, DomainError> {
+        let score = doc! { "score": { "$meta": "textScore" } };
         let mut cursor = self
             .collection
-            .find(filter)
+            .find(doc! { "$text": { "$search": query }, "available": true, "deleted_at": null })
+            .projection(score.clone())
+            .sort(score)
+            .limit(i64::from(limit))
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
@@ -116,45 +291,302 @@ impl OfferRepository for MongoOfferRepository {
     }
 
     async fn update(&self, offer: &Offer) -> Result<Offer, DomainError> {
-        let id_bson = uuid_to_bson(offer.id)?;
-        let filter = doc! { "id": id_bson };
+        let updated = Offer {
+            version: offer.version + 1,
+            ..offer.clone()
+        };
+        // Offers written before versioning have no version field: they are version 0
+        let filter = doc! {
+            "id": uuid_to_bson(offer.id)?,
+            "$expr": { "$eq": [{ "$ifNull": ["$version", 0] }, version_to_bson(offer.version)?] },
+        };
 
         let result = self
-            .collection
-            .replace_one(filter, offer)
+            .documents
+            .replace_one(filter, offer_to_document(&updated)?)
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
         if result.matched_count == 0 {
-            return Err(DomainError::NotFound(format!(
-                "Offer with id {} not found",
-                offer.id
-            )));
+            return Err(self
+                .conditional_update_failure(
+                    offer.id,
+                    DomainError::PreconditionFailed(format!(
+                        "Offer with id {} was modified since version {}",
+                        offer.id, offer.version
+                    )),
+                )
+                .await);
         }
 
-        Ok(offer.clone())
+        Ok(updated)
+    }
+
+    async fn archive(&self, id: Uuid, deleted_at: DateTime<Utc>) -> Result<Offer, DomainError> {
+        let deleted_at = mongodb::bson::to_bson(&deleted_at)
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        // `null` also matches documents written before soft deletion existed
+        self.collection
+            .find_one_and_update(
+                doc! { "id": uuid_to_bson(id)?, "deleted_at": null },
+                doc! { "$set": { "deleted_at": deleted_at }, "$inc": { "version": 1 } },
+            )
+            .return_document(ReturnDocument::After)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))
+    }
+
+    async fn restore(&self, id: Uuid) -> Result<Offer, DomainError> {
+        match self
+            .collection
+            .find_one_and_update(
+                doc! { "id": uuid_to_bson(id)?, "deleted_at": { "$ne": null } },
+                doc! { "$set": { "deleted_at": null }, "$inc": { "version": 1 } },
+            )
+            .return_document(ReturnDocument::After)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        {
+            Some(offer) => Ok(offer),
+            None => Err(self
+                .conditional_update_failure(
+                    id,
+                    DomainError::Conflict(format!("Offer with id {} is not archived", id)),
+                )
+                .await),
+        }
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+    async fn reserve_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
         let id_bson = uuid_to_bson(id)?;
-        let filter = doc! { "id": id_bson };
-        let result = self
+        // Single conditional update: only matches while a seat is left,
+        // so concurrent reservations can never overbook the offer.
+        // Hidden and expired offers still take acceptances of their applications.
+        let filter = doc! {
+            "id": id_bson,
+            "deleted_at": null,
+            "$expr": { "$lt": [{ "$ifNull": ["$reserved_seats", 0] }, { "$ifNull": ["$seats", 1] }] },
+        };
+        let reserved = doc! { "$add": [{ "$ifNull": ["$reserved_seats", 0] }, 1] };
+        let update = vec![doc! {
+            "$set": {
+                "reserved_seats": reserved.clone(),
+                "available": { "$and": ["$available", { "$lt": [reserved, { "$ifNull": ["$seats", 1] }] }] },
+                "version": next_version(),
+            }
+        }];
+
+        match self
+            .collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        {
+            Some(offer) => Ok(offer),
+            None => Err(self
+                .conditional_update_failure(
+                    id,
+                    DomainError::Conflict(format!("Offer with id {} has no seats left", id)),
+                )
+                .await),
+        }
+    }
+
+    async fn release_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
+        let id_bson = uuid_to_bson(id)?;
+        let filter = doc! { "id": id_bson, "reserved_seats": { "$gt": 0 } };
+        // A full offer was hidden by reserve_seat: freeing a seat re-opens it
+        let update = vec![doc! {
+            "$set": {
+                "available": {
+                    "$cond": [
+                        { "$gte": ["$reserved_seats", { "$ifNull": ["$seats", 1] }] },
+                        true,
+                        "$available",
+                    ]
+                },
+                "reserved_seats": { "$subtract": ["$reserved_seats", 1] },
+                "version": next_version(),
+            }
+        }];
+
+        match self
+            .collection
+            .find_one_and_update(filter, update)
+            .return_document(ReturnDocument::After)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        {
+            Some(offer) => Ok(offer),
+            None => Err(self
+                .conditional_update_failure(
+                    id,
+                    DomainError::Conflict(format!("Offer with id {} has no reserved seat", id)),
+                )
+                .await),
+        }
+    }
+
+    async fn expire_due(&self, today: NaiveDate) -> Result<Vec<Offer>, DomainError> {
+        // Dates are stored as "YYYY-MM-DD" strings, which compare chronologically
+        let today = today.to_string();
+        let mut cursor = self
             .collection
-            .delete_one(filter)
+            .find(doc! {
+                "available": true,
+                "deleted_at": null,
+                "$or": [
+                    { "application_deadline": { "$lt": today.as_str() } },
+                    { "application_deadline": null, "end_date": { "$lt": today.as_str() } },
+                ],
+            })
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
-        if result.deleted_count == 0 {
-            return Err(DomainError::NotFound(format!(
-                "Offer with id {} not found",
-                id
-            )));
+        let mut due = Vec::new();
+        while cursor
+            .advance()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        {
+            let offer: Offer = cursor
+                .deserialize_current()
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            due.push(offer.id);
         }
-        Ok(())
+
+        // Conditional per offer: one hidden or archived meanwhile is not reported as expired
+        let mut expired = Vec::new();
+        for id in due {
+            if let Some(offer) = self
+                .collection
+                .find_one_and_update(
+                    doc! { "id": uuid_to_bson(id)?, "available": true, "deleted_at": null },
+                    doc! { "$set": { "available": false }, "$inc": { "version": 1 } },
+                )
+                .return_document(ReturnDocument::After)
+                .await
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            {
+                expired.push(offer);
+            }
+        }
+        Ok(expired)
     }
 }
 
-fn uuid_to_bson(id: Uuid) -> Result<Bson, DomainError> {
+/// Serializes an offer with the canonical keys that queries match on.
+fn offer_to_document(offer: &Offer) -> Result<Document, DomainError> {
+    let mut document = mongodb::bson::to_document(offer)
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+    document.insert("city_key", canonical_key(&offer.city));
+    document.insert("domain_key", canonical_key(&offer.domain));
+    let skill_keys: Vec<String> = offer.skills.iter().map(|s| canonical_key(s)).collect();
+    document.insert("skill_keys", skill_keys);
+    // Levels are stored as ranks so that "at most this level" is a plain $lte
+    let language_keys: Vec<Document> = offer
+        .languages
+        .iter()
+        .map(|l| doc! { "key": canonical_key(&l.language), "rank": l.level.rank() as i32 })
+        .collect();
+    document.insert("language_keys", language_keys);
+    Ok(document)
+}
+
+/// Compiles every criterion into a single query document.
+fn offer_filter_to_document(
+    filter: &OfferFilter,
+    visibility: OfferVisibility,
+) -> Result<Document, DomainError> {
+    let mut query = match visibility {
+        OfferVisibility::Available => doc! { "available": true, "deleted_at": null },
+        OfferVisibility::Hidden => doc! { "available": false, "deleted_at": null },
+        OfferVisibility::Archived => doc! { "deleted_at": { "$ne": null } },
+        OfferVisibility::All => Document::new(),
+    };
+    if let Some(ref domain) = filter.domain {
+        query.insert("domain_key", canonical_key(domain));
+    }
+    if let Some(ref city) = filter.city {
+        query.insert("city_key", canonical_key(city));
+    }
+    if let Some(company_id) = filter.company_id {
+        // Same binary encoding as the serialized offer
+        query.insert("company_id", uuid_to_bson(company_id)?);
+    }
+    if let Some(ref skill) = filter.skill {
+        query.insert("skill_keys", canonical_key(skill));
+    }
+    if let Some(ref language) = filter.language {
+        let mut requirement = doc! { "key": canonical_key(language) };
+        if let Some(level) = filter.language_level {
+            requirement.insert("rank", doc! { "$lte": level.rank() as i32 });
+        }
+        query.insert("language_keys", doc! { "$elemMatch": requirement });
+    }
+    if let Some(policy) = filter.remote_policy {
+        query.insert("remote_policy", policy.as_str());
+    }
+
+    // Bounds are already in the base currency (see OfferFilter::in_base_currency)
+    let mut salary = Document::new();
+    if let Some(min) = filter.min_salary {
+        salary.insert("$gte", minor_units(min));
+    }
+    if let Some(max) = filter.max_salary {
+        salary.insert("$lte", minor_units(max));
+    }
+    if !salary.is_empty() {
+        query.insert("base_salary.amount", salary);
+    }
+
+    // Dates are stored as "YYYY-MM-DD" strings, which compare chronologically
+    if let Some(date) = filter.start_after {
+        query.insert("start_date", doc! { "$gte": date.to_string() });
+    }
+    if let Some(date) = filter.end_before {
+        query.insert("end_date", doc! { "$lte": date.to_string() });
+    }
+
+    if let Some(ref text) = filter.text {
+        query.insert(
+            "title",
+            doc! { "$regex": escape_regex(text), "$options": "i" },
+        );
+    }
+    Ok(query)
+}
+
+/// Escapes user input so it is matched literally by a `$regex` query.
+fn escape_regex(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if "\\.+*?()|[]{}^$#&-~".contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Aggregation expression of the version after a pipeline update.
+fn next_version() -> Document {
+    doc! { "$add": [{ "$ifNull": ["$version", 0] }, 1] }
+}
+
+fn version_to_bson(version: u64) -> Result<Bson, DomainError> {
+    i64::try_from(version)
+        .map(Bson::Int64)
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+}
+
+fn to_bson<T: serde::Serialize>(value: &T) -> Result<Bson, DomainError> {
+    mongodb::bson::to_bson(value).map_err(|e| DomainError::InfrastructureError(e.to_string()))
+}
+
+pub(crate) fn uuid_to_bson(id: Uuid) -> Result<Bson, DomainError> {
     Ok(Bson::Binary(mongodb::bson::Binary {
         subtype: mongodb::bson::spec::BinarySubtype::Generic,
         bytes: id.into_bytes().to_vec(),
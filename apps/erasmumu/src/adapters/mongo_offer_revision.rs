@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use mongodb::bson::doc;
+use mongodb::{Client, Collection, IndexModel};
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::offer::OfferRevision;
+use zukmove_core::domain::ports::{DomainError, OfferRevisionRepository};
+
+use super::mongo_offer::uuid_to_bson;
+
+/// Append-only collection: revisions are inserted, never updated nor deleted.
+pub struct MongoOfferRevisionRepository {
+    collection: Collection<OfferRevision>,
+}
+
+impl MongoOfferRevisionRepository {
+    pub fn new(client: &Client, database: &str) -> Self {
+        let db = client.database(database);
+        let collection = db.collection::<OfferRevision>("offer_revisions");
+        Self { collection }
+    }
+
+    /// Creates the index used to read the history of an offer. Idempotent.
+    pub async fn ensure_indexes(&self) -> Result<(), DomainError> {
+        let history_index = IndexModel::builder()
+            .keys(doc! { "offer_id": 1, "version": 1 })
+            .build();
+        self.collection
+            .create_index(history_index)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl OfferRevisionRepository for MongoOfferRevisionRepository {
+    async fn save(&self, revision: &OfferRevision) -> Result<OfferRevision, DomainError> {
+        self.collection
+            .insert_one(revision)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        Ok(revision.clone())
+    }
+
+    async fn find_by_offer(&self, offer_id: Uuid) -> Result<Vec<OfferRevision>, DomainError> {
+        let mut cursor = self
+            .collection
+            .find(doc! { "offer_id": uuid_to_bson(offer_id)? })
+            .sort(doc! { "version": 1, "changed_at": 1 })
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let mut revisions = Vec::new();
+        while cursor
+            .advance()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        {
+            let revision = cursor
+                .deserialize_current()
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+            revisions.push(revision);
+        }
+        Ok(revisions)
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+
+use zukmove_core::domain::entities::money::ExchangeRates;
+use zukmove_core::domain::ports::{DomainError, ExchangeRateProvider};
+
+/// Contents of the rates file: `{ "base": "EUR", "rates": { "GBP": 0.85 } }`.
+#[derive(serde::Deserialize)]
+struct RatesFile {
+    base: String,
+    rates: BTreeMap<String, f64>,
+}
+
+/// Exchange rates read once from a JSON file; restart the service to pick up new rates.
+pub struct StaticExchangeRateProvider {
+    rates: ExchangeRates,
+}
+
+impl StaticExchangeRateProvider {
+    pub fn new(rates: ExchangeRates) -> Self {
+        Self { rates }
+    }
+
+    pub fn from_file(path: &str) -> Result<Self, DomainError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            DomainError::InfrastructureError(format!("Cannot read {}: {}", path, e))
+        })?;
+        let file: RatesFile = serde_json::from_str(&content).map_err(|e| {
+            DomainError::InfrastructureError(format!("Invalid rates file {}: {}", path, e))
+        })?;
+        Ok(Self::new(ExchangeRates::from_decimal(
+            &file.base, file.rates,
+        )?))
+    }
+}
+
+#[async_trait]
+impl ExchangeRateProvider for StaticExchangeRateProvider {
+    async fn rates(&self) -> Result<ExchangeRates, DomainError> {
+        Ok(self.rates.clone())
+    }
+}
@@ -0,0 +1,11 @@
+use chrono::{DateTime, Utc};
+
+use zukmove_core::domain::ports::Clock;
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
@@ -0,0 +1,36 @@
+use std::time::Duration;
+
+use actix_web::web;
+
+use zukmove_core::domain::entities::offer::Offer;
+use zukmove_core::domain::ports::DomainError;
+
+use crate::AppState;
+use crate::routes::offer::publish_offer_event;
+
+/// Hides every offer past its deadline, as of the state's clock, and publishes
+/// `offer.expired` for each of them.
+pub async fn expire_offers(state: &AppState) -> Result<Vec<Offer>, DomainError> {
+    let expired = state.offer_repo.expire_due(state.clock.today()).await?;
+    for offer in &expired {
+        publish_offer_event(state, "offer.expired", offer).await;
+    }
+    Ok(expired)
+}
+
+/// Runs `expire_offers` every `interval`, the first time right away.
+pub fn spawn_scheduler(state: web::Data<AppState>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            match expire_offers(&state).await {
+                Ok(expired) if !expired.is_empty() => {
+                    log::info!("{} offer(s) expired", expired.len())
+                }
+                Ok(_) => {}
+                Err(e) => log::error!("Offer expiry failed: {}", e),
+            }
+        }
+    });
+}
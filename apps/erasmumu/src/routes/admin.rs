@@ -0,0 +1,184 @@
+use actix_web::{HttpRequest, HttpResponse, web};
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::offer::{
+    CefrLevel, Offer, OfferFilter, OfferVisibility, RemotePolicy,
+};
+use zukmove_core::domain::pagination::{Page, PageRequest};
+use zukmove_core::domain::ports::DomainError;
+
+use super::offer::{
+    PageQuery, author, domain_error_to_response, publish_offer_event, record_offer_update,
+    salary_bounds_in_base_currency, versioned,
+};
+use crate::AppState;
+
+#[derive(serde::Deserialize)]
+pub struct VisibilityQuery {
+    pub visibility: Option<OfferVisibility>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/offer",
+    params(
+        ("visibility" = inline(Option<OfferVisibility>), Query, description = "available, hidden, archived or all (default)"),
+        ("domain" = inline(Option<String>), Query, description = "Filter by domain"),
+        ("city" = inline(Option<String>), Query, description = "Filter by city"),
+        ("min_salary" = inline(Option<f64>), Query, description = "Minimum monthly salary"),
+        ("max_salary" = inline(Option<f64>), Query, description = "Maximum monthly salary"),
+        ("currency" = inline(Option<String>), Query, description = "ISO 4217 currency of the salary bounds (default: base currency of the exchange rates)"),
+        ("start_after" = inline(Option<chrono::NaiveDate>), Query, description = "Internship starts on or after this date"),
+        ("end_before" = inline(Option<chrono::NaiveDate>), Query, description = "Internship ends on or before this date"),
+        ("text" = inline(Option<String>), Query, description = "Case-insensitive text contained in the title"),
+        ("company_id" = inline(Option<Uuid>), Query, description = "Offers of this company"),
+        ("skill" = inline(Option<String>), Query, description = "Skill required by the offer"),
+        ("language" = inline(Option<String>), Query, description = "Language required by the offer"),
+        ("language_level" = inline(Option<CefrLevel>), Query, description = "With language: offers requiring at most this CEFR level"),
+        ("remote_policy" = inline(Option<RemotePolicy>), Query, description = "on_site, hybrid or remote"),
+        ("limit" = inline(Option<u32>), Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = inline(Option<String>), Query, description = "next_cursor of the previous page"),
+        ("sort" = inline(Option<String>), Query, description = "start_date, title, city or salary; prefix with '-' for descending order")
+    ),
+    responses(
+        (status = 200, description = "Page of offers, hidden and archived ones included", body = Page<Offer>),
+        (status = 400, description = "Invalid filter or pagination parameters"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_all_offers(
+    state: web::Data<AppState>,
+    filter: web::Query<OfferFilter>,
+    visibility: web::Query<VisibilityQuery>,
+    query: web::Query<PageQuery>,
+) -> HttpResponse {
+    let page = match PageRequest::parse::<Offer>(
+        query.limit,
+        query.cursor.as_deref(),
+        query.sort.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if let Err(e) = filter.validate() {
+        return domain_error_to_response(e);
+    }
+    let filter = match salary_bounds_in_base_currency(&state, filter.into_inner()).await {
+        Ok(filter) => filter,
+        Err(e) => return domain_error_to_response(e),
+    };
+
+    let visibility = visibility.visibility.unwrap_or(OfferVisibility::All);
+    match state.offer_repo.search(&filter, visibility, &page).await {
+        Ok(offers) => HttpResponse::Ok().json(offers),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/admin/offer/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Offer ID")
+    ),
+    responses(
+        (status = 200, description = "Offer found, even hidden or archived, with its version as ETag", body = Offer),
+        (status = 404, description = "Offer not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_any_offer(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    match state
+        .offer_repo
+        .find_by_id_including_unavailable(path.into_inner())
+        .await
+    {
+        Ok(o) => versioned(&o, o.version),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/offer/{id}/reactivate",
+    params(
+        ("id" = Uuid, Path, description = "Offer ID"),
+        ("X-User" = inline(Option<String>), Header, description = "Author of the reactivation, recorded in the offer history")
+    ),
+    responses(
+        (status = 200, description = "Offer available again (records a revision and publishes offer.updated and offer.reactivated)", body = Offer),
+        (status = 404, description = "Offer not found"),
+        (status = 409, description = "Offer is archived, past its deadline, or full: restore it, move the deadline or add seats first"),
+        (status = 412, description = "Offer modified while being reactivated: retry"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn reactivate_offer(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+) -> HttpResponse {
+    let id = path.into_inner();
+    let offer = match state.offer_repo.find_by_id_including_unavailable(id).await {
+        Ok(o) => o,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if offer.is_archived() {
+        return domain_error_to_response(DomainError::Conflict(format!(
+            "Offer with id {} is archived",
+            id
+        )));
+    }
+    if offer.available {
+        return HttpResponse::Ok().json(offer);
+    }
+    if offer.is_expired(state.clock.today()) {
+        return domain_error_to_response(DomainError::Conflict(format!(
+            "Offer with id {} is past its deadline",
+            id
+        )));
+    }
+    // A full offer is hidden by reserve_seat: showing it again would only lead to refusals
+    if offer.remaining_seats() == 0 {
+        return domain_error_to_response(DomainError::Conflict(format!(
+            "Offer with id {} has no seats left",
+            id
+        )));
+    }
+
+    let reactivated = Offer {
+        available: true,
+        ..offer.clone()
+    };
+    match state.offer_repo.update(&reactivated).await {
+        Ok(o) => {
+            record_offer_update(&state, &offer, &o, author(&req)).await;
+            publish_offer_event(&state, "offer.reactivated", &o).await;
+            HttpResponse::Ok().json(o)
+        }
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/admin/offer/{id}/restore",
+    params(
+        ("id" = Uuid, Path, description = "Offer ID")
+    ),
+    responses(
+        (status = 200, description = "Offer restored (publishes offer.restored)", body = Offer),
+        (status = 404, description = "Offer not found"),
+        (status = 409, description = "Offer is not archived"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn restore_offer(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    match state.offer_repo.restore(path.into_inner()).await {
+        Ok(o) => {
+            publish_offer_event(&state, "offer.restored", &o).await;
+            HttpResponse::Ok().json(o)
+        }
+        Err(e) => domain_error_to_response(e),
+    }
+}
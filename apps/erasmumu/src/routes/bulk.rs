@@ -0,0 +1,512 @@
+use std::collections::HashMap;
+
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, web};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::money::{Money, SalaryPeriod};
+use zukmove_core::domain::entities::offer::{
+    CefrLevel, CreateOfferRequest, LanguageRequirement, Offer, OfferFilter, OfferVisibility,
+    RemotePolicy,
+};
+use zukmove_core::domain::pagination::PageRequest;
+use zukmove_core::domain::ports::DomainError;
+use zukmove_core::domain::validation::{FieldError, Validate};
+
+use super::company::check_company_exists;
+use super::domain::load_taxonomy;
+use super::offer::{domain_error_to_response, new_offer, publish_offer_events};
+use crate::AppState;
+
+/// Largest import accepted, in bytes.
+pub const MAX_IMPORT_SIZE: usize = 10 * 1024 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TransferFormat {
+    Csv,
+    Ndjson,
+}
+
+impl TransferFormat {
+    fn content_type(&self) -> &'static str {
+        match self {
+            TransferFormat::Csv => "text/csv",
+            TransferFormat::Ndjson => "application/x-ndjson",
+        }
+    }
+
+    /// The `format` parameter wins over the request's content type.
+    fn of_request(format: Option<Self>, req: &HttpRequest) -> Result<Self, DomainError> {
+        if let Some(format) = format {
+            return Ok(format);
+        }
+        let content_type = req
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        match content_type.split(';').next().unwrap_or_default().trim() {
+            "text/csv" => Ok(TransferFormat::Csv),
+            "application/x-ndjson" | "application/jsonl" => Ok(TransferFormat::Ndjson),
+            other => Err(DomainError::ValidationError(format!(
+                "Unsupported import format {:?}: use text/csv or application/x-ndjson",
+                other
+            ))),
+        }
+    }
+}
+
+/// One CSV line. Lists are `;`-separated, a `;` or `\` inside an item being
+/// escaped with `\`; languages are written `Deutsch:B2`. `salary_amount` is in
+/// minor units of `salary_currency` (`150000` EUR is 1 500 €).
+#[derive(Debug, Serialize, Deserialize)]
+struct OfferRecord {
+    #[serde(default)]
+    id: Option<Uuid>,
+    title: String,
+    link: String,
+    city: String,
+    domain: String,
+    salary_amount: i64,
+    salary_currency: String,
+    #[serde(default)]
+    salary_period: Option<SalaryPeriod>,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    available: bool,
+    #[serde(default)]
+    seats: Option<u32>,
+    #[serde(default)]
+    application_deadline: Option<NaiveDate>,
+    #[serde(default)]
+    company_id: Option<Uuid>,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    skills: Option<String>,
+    #[serde(default)]
+    languages: Option<String>,
+    #[serde(default)]
+    remote_policy: Option<RemotePolicy>,
+}
+
+impl OfferRecord {
+    fn from_offer(offer: &Offer) -> Self {
+        let languages: Vec<String> = offer
+            .languages
+            .iter()
+            .map(|l| format!("{}:{}", l.language, l.level.as_str()))
+            .collect();
+        Self {
+            id: Some(offer.id),
+            title: offer.title.clone(),
+            link: offer.link.clone(),
+            city: offer.city.clone(),
+            domain: offer.domain.clone(),
+            salary_amount: offer.salary.amount,
+            salary_currency: offer.salary.currency.clone(),
+            salary_period: Some(offer.salary.period),
+            start_date: offer.start_date,
+            end_date: offer.end_date,
+            available: offer.available,
+            seats: Some(offer.seats),
+            application_deadline: offer.application_deadline,
+            company_id: offer.company_id,
+            description: offer.description.clone(),
+            skills: Some(join_list(&offer.skills)),
+            languages: Some(join_list(&languages)),
+            remote_policy: Some(offer.remote_policy),
+        }
+    }
+
+    /// The `id` column of an export is ignored: imported offers always get a new id.
+    fn into_request(self) -> Result<CreateOfferRequest, FieldError> {
+        let languages = split_list(self.languages.as_deref())
+            .iter()
+            .map(|entry| {
+                let (language, level) = entry.rsplit_once(':').ok_or_else(|| {
+                    field_error("languages", "must be language:LEVEL pairs separated by ';'")
+                })?;
+                let level = serde_json::from_value::<CefrLevel>(level.trim().into())
+                    .map_err(|_| field_error("languages", "levels must be A1 to C2"))?;
+                Ok(LanguageRequirement {
+                    language: language.trim().to_string(),
+                    level,
+                })
+            })
+            .collect::<Result<Vec<_>, FieldError>>()?;
+
+        Ok(CreateOfferRequest {
+            title: self.title,
+            link: self.link,
+            city: self.city,
+            domain: self.domain,
+            salary: Money::new(
+                self.salary_amount,
+                &self.salary_currency,
+                self.salary_period.unwrap_or_default(),
+            ),
+            start_date: self.start_date,
+            end_date: self.end_date,
+            available: self.available,
+            seats: self.seats.unwrap_or(1),
+            application_deadline: self.application_deadline,
+            company_id: self.company_id,
+            description: self.description.filter(|d| !d.is_empty()),
+            skills: split_list(self.skills.as_deref()),
+            languages,
+            remote_policy: self.remote_policy.unwrap_or_default(),
+        })
+    }
+}
+
+/// Joins list items with `;`, escaping the `;` and `\` they contain.
+fn join_list(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|item| item.replace('\\', "\\\\").replace(';', "\\;"))
+        .collect::<Vec<_>>()
+        .join(";")
+}
+
+/// Reverse of `join_list`: blank items are dropped.
+fn split_list(value: Option<&str>) -> Vec<String> {
+    let mut items = Vec::new();
+    let mut item = String::new();
+    let mut chars = value.unwrap_or_default().chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => item.extend(chars.next()),
+            ';' => items.push(std::mem::take(&mut item)),
+            c => item.push(c),
+        }
+    }
+    items.push(item);
+    items
+        .into_iter()
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect()
+}
+
+fn field_error(field: &str, message: &str) -> FieldError {
+    FieldError {
+        field: field.to_string(),
+        message: message.to_string(),
+    }
+}
+
+/// Outcome of one line of an import.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportRow {
+    /// Line in the uploaded file, the CSV header being line 1.
+    pub line: u64,
+    /// Id of the offer created from the line; absent when nothing was written.
+    pub offer_id: Option<Uuid>,
+    /// Empty when the line is valid.
+    pub errors: Vec<FieldError>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct ImportReport {
+    pub dry_run: bool,
+    /// Number of offers written: 0 on a dry run or when any line is invalid.
+    pub imported: usize,
+    pub rows: Vec<ImportRow>,
+}
+
+#[derive(Deserialize)]
+pub struct ImportQuery {
+    pub format: Option<TransferFormat>,
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// Parses every line of the upload, keeping parse errors per line.
+fn parse_rows(
+    format: TransferFormat,
+    body: &[u8],
+) -> Vec<(u64, Result<CreateOfferRequest, FieldError>)> {
+    match format {
+        TransferFormat::Csv => {
+            let mut reader = csv::ReaderBuilder::new()
+                .trim(csv::Trim::All)
+                .from_reader(body);
+            let headers = reader.headers().cloned().unwrap_or_default();
+            reader
+                .records()
+                .map(|record| match record {
+                    Ok(record) => {
+                        let line = record.position().map_or(0, |p| p.line());
+                        let request = record
+                            .deserialize::<OfferRecord>(Some(&headers))
+                            .map_err(|e| csv_error(&e, &headers))
+                            .and_then(OfferRecord::into_request);
+                        (line, request)
+                    }
+                    Err(e) => {
+                        let line = e.position().map_or(0, |p| p.line());
+                        (line, Err(csv_error(&e, &headers)))
+                    }
+                })
+                .collect()
+        }
+        TransferFormat::Ndjson => String::from_utf8_lossy(body)
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| {
+                let request = serde_json::from_str::<CreateOfferRequest>(line)
+                    .map_err(|e| field_error("record", &e.to_string()));
+                (i as u64 + 1, request)
+            })
+            .collect(),
+    }
+}
+
+/// Names the column a CSV error comes from, when the reader knows it.
+fn csv_error(error: &csv::Error, headers: &csv::StringRecord) -> FieldError {
+    if let csv::ErrorKind::Deserialize { err, .. } = error.kind() {
+        let column = err
+            .field()
+            .and_then(|i| headers.get(i as usize))
+            .unwrap_or("record");
+        return field_error(column, &err.kind().to_string());
+    }
+    field_error("record", &error.to_string())
+}
+
+#[utoipa::path(
+    post,
+    path = "/offer/import",
+    params(
+        ("format" = inline(Option<TransferFormat>), Query, description = "csv or ndjson; defaults to the Content-Type (text/csv or application/x-ndjson)"),
+        ("dry_run" = inline(Option<bool>), Query, description = "Validate every line without writing anything")
+    ),
+    request_body(content = String, description = "CSV with a header line (columns of GET /offer/export) or one CreateOfferRequest JSON per line", content_type = "text/csv"),
+    responses(
+        (status = 200, description = "Dry run: every line is valid", body = ImportReport),
+        (status = 201, description = "Every line imported (publishes offer.created for each)", body = ImportReport),
+        (status = 400, description = "Unsupported format"),
+        (status = 422, description = "Some lines are invalid: nothing was imported", body = ImportReport),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn import_offers(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<ImportQuery>,
+    body: web::Bytes,
+) -> HttpResponse {
+    let format = match TransferFormat::of_request(query.format, &req) {
+        Ok(format) => format,
+        Err(e) => return domain_error_to_response(e),
+    };
+    let taxonomy = match load_taxonomy(&state).await {
+        Ok(taxonomy) => taxonomy,
+        Err(e) => return domain_error_to_response(e),
+    };
+    let rates = match state.exchange_rates.rates().await {
+        Ok(rates) => rates,
+        Err(e) => return domain_error_to_response(e),
+    };
+
+    // Each company is looked up once, however many lines reference it
+    let mut known_companies: HashMap<Uuid, bool> = HashMap::new();
+    let mut offers = Vec::new();
+    let mut rows = Vec::new();
+    for (line, request) in parse_rows(format, &body) {
+        let mut errors = Vec::new();
+        if let Ok(ref request) = request {
+            if let Err(e) = request.validate() {
+                errors.extend(field_errors(e, "record"));
+            }
+            let domain = taxonomy
+                .canonical_name(&request.domain)
+                .map_err(|e| errors.extend(field_errors(e, "domain")));
+            let base_salary = rates
+                .to_base(&request.salary)
+                .map_err(|e| errors.extend(field_errors(e, "salary.currency")));
+            if let Some(company_id) = request.company_id {
+                let exists = match known_companies.get(&company_id) {
+                    Some(exists) => *exists,
+                    None => match check_company_exists(&state, company_id).await {
+                        Ok(()) => true,
+                        Err(DomainError::ValidationError(_)) => false,
+                        Err(e) => return domain_error_to_response(e),
+                    },
+                };
+                known_companies.insert(company_id, exists);
+                if !exists {
+                    errors.push(field_error("company_id", "unknown company"));
+                }
+            }
+            if let (true, Ok(domain), Ok(base_salary)) = (errors.is_empty(), domain, base_salary) {
+                offers.push(new_offer(request, domain, base_salary));
+            }
+        }
+        if let Err(error) = request {
+            errors.push(error);
+        }
+        rows.push(ImportRow {
+            line,
+            offer_id: None,
+            errors,
+        });
+    }
+
+    let all_valid = rows.iter().all(|row| row.errors.is_empty());
+    let mut report = ImportReport {
+        dry_run: query.dry_run,
+        imported: 0,
+        rows,
+    };
+    if !all_valid {
+        return HttpResponse::UnprocessableEntity().json(report);
+    }
+    if query.dry_run {
+        return HttpResponse::Ok().json(report);
+    }
+
+    if let Err(e) = state.offer_repo.save_many(&offers).await {
+        return domain_error_to_response(e);
+    }
+    publish_offer_events(&state, "offer.created", &offers).await;
+    for (row, offer) in report.rows.iter_mut().zip(&offers) {
+        row.offer_id = Some(offer.id);
+    }
+    report.imported = offers.len();
+    HttpResponse::Created().json(report)
+}
+
+/// Field errors of a rejected line; other errors are reported on `field`.
+fn field_errors(error: DomainError, field: &str) -> Vec<FieldError> {
+    match error {
+        DomainError::InvalidFields(errors) => errors,
+        other => vec![field_error(field, &other.to_string())],
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ExportQuery {
+    pub format: Option<TransferFormat>,
+    pub visibility: Option<OfferVisibility>,
+}
+
+/// Offers read per page while exporting.
+const EXPORT_BATCH: u32 = PageRequest::MAX_LIMIT;
+
+#[utoipa::path(
+    get,
+    path = "/offer/export",
+    params(
+        ("format" = inline(Option<TransferFormat>), Query, description = "csv (default) or ndjson"),
+        ("visibility" = inline(Option<OfferVisibility>), Query, description = "available, hidden, archived or all (default)")
+    ),
+    responses(
+        (status = 200, description = "Every offer, streamed in the requested format", content_type = "text/csv"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn export_offers(
+    state: web::Data<AppState>,
+    query: web::Query<ExportQuery>,
+) -> HttpResponse {
+    let format = query.format.unwrap_or(TransferFormat::Csv);
+    let visibility = query.visibility.unwrap_or(OfferVisibility::All);
+
+    // Pages are read one after the other as the client consumes the body
+    let first = PageRequest::first::<Offer>(EXPORT_BATCH);
+    let stream = futures::stream::unfold(Some((first, true)), move |next| {
+        let state = state.clone();
+        async move {
+            let (page, first) = next?;
+            let result = state
+                .offer_repo
+                .search(&OfferFilter::default(), visibility, &page)
+                .await;
+            let offers = match result {
+                Ok(offers) => offers,
+                Err(e) => {
+                    log::error!("Offer export failed: {}", e);
+                    return Some((Err(actix_web::error::ErrorInternalServerError(e)), None));
+                }
+            };
+            let next = offers.next_cursor.as_deref().and_then(|cursor| {
+                PageRequest::parse::<Offer>(Some(EXPORT_BATCH), Some(cursor), None)
+                    .ok()
+                    .map(|page| (page, false))
+            });
+            let chunk = encode(format, &offers.items, first)
+                .map_err(actix_web::error::ErrorInternalServerError);
+            Some((chunk, next))
+        }
+    });
+
+    HttpResponse::Ok()
+        .content_type(format.content_type())
+        .streaming(stream)
+}
+
+/// Encodes a batch of offers; the CSV header is only written before the first batch.
+fn encode(
+    format: TransferFormat,
+    offers: &[Offer],
+    first: bool,
+) -> Result<web::Bytes, DomainError> {
+    let infrastructure =
+        |e: &dyn std::fmt::Display| DomainError::InfrastructureError(e.to_string());
+    let mut buffer = Vec::new();
+    match format {
+        TransferFormat::Csv => {
+            let mut writer = csv::WriterBuilder::new()
+                .has_headers(first)
+                .from_writer(&mut buffer);
+            for offer in offers {
+                writer
+                    .serialize(OfferRecord::from_offer(offer))
+                    .map_err(|e| infrastructure(&e))?;
+            }
+            // A header is still due when the first batch is empty
+            if first && offers.is_empty() {
+                writer
+                    .write_record(CSV_COLUMNS)
+                    .map_err(|e| infrastructure(&e))?;
+            }
+            writer.flush().map_err(|e| infrastructure(&e))?;
+        }
+        TransferFormat::Ndjson => {
+            for offer in offers {
+                serde_json::to_writer(&mut buffer, offer).map_err(|e| infrastructure(&e))?;
+                buffer.push(b'\n');
+            }
+        }
+    }
+    Ok(web::Bytes::from(buffer))
+}
+
+/// Columns of `OfferRecord`, in order.
+const CSV_COLUMNS: [&str; 18] = [
+    "id",
+    "title",
+    "link",
+    "city",
+    "domain",
+    "salary_amount",
+    "salary_currency",
+    "salary_period",
+    "start_date",
+    "end_date",
+    "available",
+    "seats",
+    "application_deadline",
+    "company_id",
+    "description",
+    "skills",
+    "languages",
+    "remote_policy",
+];
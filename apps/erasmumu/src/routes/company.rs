@@ -0,0 +1,181 @@
+use actix_web::{HttpResponse, web};
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::company::{
+    Company, CreateCompanyRequest, UpdateCompanyRequest,
+};
+use zukmove_core::domain::entities::offer::{Offer, OfferFilter, OfferVisibility};
+use zukmove_core::domain::pagination::PageRequest;
+use zukmove_core::domain::ports::DomainError;
+use zukmove_core::domain::validation::{Validate, ValidationFailure};
+
+use super::offer::domain_error_to_response;
+use crate::AppState;
+
+/// Offers may only point to a company of the registry.
+pub async fn check_company_exists(state: &AppState, id: Uuid) -> Result<(), DomainError> {
+    match state.company_repo.find_by_id(id).await {
+        Ok(_) => Ok(()),
+        Err(DomainError::NotFound(_)) => Err(DomainError::ValidationError(format!(
+            "Unknown company: {}",
+            id
+        ))),
+        Err(e) => Err(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/company",
+    responses(
+        (status = 200, description = "Companies sorted by name", body = [Company]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_companies(state: web::Data<AppState>) -> HttpResponse {
+    match state.company_repo.find_all().await {
+        Ok(companies) => HttpResponse::Ok().json(companies),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/company/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Company found", body = Company),
+        (status = 404, description = "Company not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_company(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    match state.company_repo.find_by_id(path.into_inner()).await {
+        Ok(c) => HttpResponse::Ok().json(c),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/company",
+    request_body = CreateCompanyRequest,
+    responses(
+        (status = 201, description = "Company created, not verified yet", body = Company),
+        (status = 422, description = "Invalid fields, all listed in `fields`", body = ValidationFailure),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn create_company(
+    state: web::Data<AppState>,
+    body: web::Json<CreateCompanyRequest>,
+) -> HttpResponse {
+    if let Err(e) = body.validate() {
+        return domain_error_to_response(e);
+    }
+
+    let company = Company {
+        id: Uuid::new_v4(),
+        name: body.name.trim().to_string(),
+        country: body.country.trim().to_string(),
+        city: body.city.trim().to_string(),
+        website: body.website.clone(),
+        contact: body.contact.clone(),
+        verified: false,
+    };
+
+    match state.company_repo.save(&company).await {
+        Ok(c) => HttpResponse::Created().json(c),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/company/{id}",
+    request_body = UpdateCompanyRequest,
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 200, description = "Company updated", body = Company),
+        (status = 404, description = "Company not found"),
+        (status = 422, description = "Invalid fields, all listed in `fields`", body = ValidationFailure),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn update_company(
+    state: web::Data<AppState>,
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateCompanyRequest>,
+) -> HttpResponse {
+    if let Err(e) = body.validate() {
+        return domain_error_to_response(e);
+    }
+    let existing = match state.company_repo.find_by_id(path.into_inner()).await {
+        Ok(c) => c,
+        Err(e) => return domain_error_to_response(e),
+    };
+
+    let trimmed = |value: &Option<String>| value.as_deref().map(|v| v.trim().to_string());
+    let updated = Company {
+        id: existing.id,
+        name: trimmed(&body.name).unwrap_or(existing.name),
+        country: trimmed(&body.country).unwrap_or(existing.country),
+        city: trimmed(&body.city).unwrap_or(existing.city),
+        website: body.website.clone().or(existing.website),
+        contact: body.contact.clone().or(existing.contact),
+        verified: body.verified.unwrap_or(existing.verified),
+    };
+
+    match state.company_repo.update(&updated).await {
+        Ok(c) => HttpResponse::Ok().json(c),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/company/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Company ID")
+    ),
+    responses(
+        (status = 204, description = "Company deleted"),
+        (status = 404, description = "Company not found"),
+        (status = 409, description = "Offers, archived ones included, still reference the company"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn delete_company(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    let id = path.into_inner();
+    let filter = OfferFilter {
+        company_id: Some(id),
+        ..Default::default()
+    };
+    match state
+        .offer_repo
+        .search(
+            &filter,
+            OfferVisibility::All,
+            &PageRequest::first::<Offer>(1),
+        )
+        .await
+    {
+        Ok(offers) if !offers.items.is_empty() => {
+            return domain_error_to_response(DomainError::Conflict(format!(
+                "Company with id {} still has offers",
+                id
+            )));
+        }
+        Ok(_) => {}
+        Err(e) => return domain_error_to_response(e),
+    }
+
+    match state.company_repo.delete(id).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e) => domain_error_to_response(e),
+    }
+}
@@ -0,0 +1,167 @@
+use actix_web::{HttpResponse, web};
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::domain::{
+    CreateDomainRequest, Domain, DomainTaxonomy, UpdateDomainRequest,
+};
+use zukmove_core::domain::ports::DomainError;
+
+use super::offer::domain_error_to_response;
+use crate::AppState;
+
+/// Loads the whole catalog: it is small and every check needs the hierarchy.
+pub async fn load_taxonomy(state: &AppState) -> Result<DomainTaxonomy, DomainError> {
+    Ok(DomainTaxonomy::new(state.domain_repo.find_all().await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/domain",
+    responses(
+        (status = 200, description = "Domain catalog", body = [Domain]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_domains(state: web::Data<AppState>) -> HttpResponse {
+    match state.domain_repo.find_all().await {
+        Ok(domains) => HttpResponse::Ok().json(domains),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/domain/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Domain ID")
+    ),
+    responses(
+        (status = 200, description = "Domain found", body = Domain),
+        (status = 404, description = "Domain not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_domain(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    match state.domain_repo.find_by_id(path.into_inner()).await {
+        Ok(d) => HttpResponse::Ok().json(d),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/domain",
+    request_body = CreateDomainRequest,
+    responses(
+        (status = 201, description = "Domain created", body = Domain),
+        (status = 400, description = "Empty name or unknown parent"),
+        (status = 409, description = "Name or alias already used by another domain"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn create_domain(
+    state: web::Data<AppState>,
+    body: web::Json<CreateDomainRequest>,
+) -> HttpResponse {
+    let domain = Domain {
+        id: Uuid::new_v4(),
+        name: body.name.trim().to_string(),
+        parent_id: body.parent_id,
+        aliases: body.aliases.clone(),
+    };
+
+    let taxonomy = match load_taxonomy(&state).await {
+        Ok(t) => t,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if let Err(e) = taxonomy.check(&domain) {
+        return domain_error_to_response(e);
+    }
+
+    match state.domain_repo.save(&domain).await {
+        Ok(d) => HttpResponse::Created().json(d),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/domain/{id}",
+    request_body = UpdateDomainRequest,
+    params(
+        ("id" = Uuid, Path, description = "Domain ID")
+    ),
+    responses(
+        (status = 200, description = "Domain updated", body = Domain),
+        (status = 400, description = "Empty name, unknown parent or cycle"),
+        (status = 404, description = "Domain not found"),
+        (status = 409, description = "Name or alias already used by another domain"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn update_domain(
+    state: web::Data<AppState>,
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateDomainRequest>,
+) -> HttpResponse {
+    let existing = match state.domain_repo.find_by_id(path.into_inner()).await {
+        Ok(d) => d,
+        Err(e) => return domain_error_to_response(e),
+    };
+
+    let updated = Domain {
+        id: existing.id,
+        name: body
+            .name
+            .as_deref()
+            .map(|n| n.trim().to_string())
+            .unwrap_or(existing.name),
+        parent_id: body.parent_id.unwrap_or(existing.parent_id),
+        aliases: body.aliases.clone().unwrap_or(existing.aliases),
+    };
+
+    let taxonomy = match load_taxonomy(&state).await {
+        Ok(t) => t,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if let Err(e) = taxonomy.check(&updated) {
+        return domain_error_to_response(e);
+    }
+
+    match state.domain_repo.update(&updated).await {
+        Ok(d) => HttpResponse::Ok().json(d),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/domain/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Domain ID")
+    ),
+    responses(
+        (status = 204, description = "Domain deleted"),
+        (status = 404, description = "Domain not found"),
+        (status = 409, description = "Domain still has sub-domains"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn delete_domain(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    let id = path.into_inner();
+    let taxonomy = match load_taxonomy(&state).await {
+        Ok(t) => t,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if taxonomy.has_children(id) {
+        return domain_error_to_response(DomainError::Conflict(format!(
+            "Domain with id {} still has sub-domains",
+            id
+        )));
+    }
+
+    match state.domain_repo.delete(id).await {
+        Ok(()) => HttpResponse::NoContent().finish(),
+        Err(e) => domain_error_to_response(e),
+    }
+}
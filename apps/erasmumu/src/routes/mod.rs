@@ -1 +1,5 @@
+pub mod admin;
+pub mod bulk;
+pub mod company;
+pub mod domain;
 pub mod offer;
@@ -1,9 +1,19 @@
-use actix_web::{HttpResponse, web};
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, web};
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::offer::{CreateOfferRequest, Offer, UpdateOfferRequest};
+use zukmove_core::domain::concurrency::{IfMatch, etag};
+use zukmove_core::domain::entities::money::Money;
+use zukmove_core::domain::entities::offer::{
+    CefrLevel, CreateOfferRequest, Offer, OfferFilter, OfferRevision, OfferUpdatedEvent,
+    OfferVisibility, RemotePolicy, UpdateOfferRequest,
+};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 use zukmove_core::domain::ports::DomainError;
+use zukmove_core::domain::validation::{Validate, ValidationFailure};
 
+use super::company::check_company_exists;
+use super::domain::load_taxonomy;
 use crate::AppState;
 
 #[utoipa::path(
@@ -12,7 +22,8 @@ use crate::AppState;
     request_body = CreateOfferRequest,
     responses(
         (status = 201, description = "Offer created", body = Offer),
-        (status = 400, description = "Validation error"),
+        (status = 400, description = "Domain missing from the catalog or unknown company"),
+        (status = 422, description = "Invalid fields, all listed in `fields`", body = ValidationFailure),
         (status = 500, description = "Internal error")
     )
 )]
@@ -20,40 +31,145 @@ pub async fn create_offer(
     state: web::Data<AppState>,
     body: web::Json<CreateOfferRequest>,
 ) -> HttpResponse {
-    let offer = Offer {
+    if let Err(e) = body.validate() {
+        return domain_error_to_response(e);
+    }
+    let domain = match load_taxonomy(&state)
+        .await
+        .and_then(|t| t.canonical_name(&body.domain))
+    {
+        Ok(domain) => domain,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if let Some(company_id) = body.company_id
+        && let Err(e) = check_company_exists(&state, company_id).await
+    {
+        return domain_error_to_response(e);
+    }
+    let base_salary = match to_base_salary(&state, &body.salary).await {
+        Ok(base) => base,
+        Err(e) => return domain_error_to_response(e),
+    };
+
+    let offer = new_offer(&body, domain, base_salary);
+    match state.offer_repo.save(&offer).await {
+        Ok(o) => {
+            publish_offer_event(&state, "offer.created", &o).await;
+            HttpResponse::Created().json(o)
+        }
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+/// Builds a new offer from a request whose domain and salary were already resolved.
+pub fn new_offer(body: &CreateOfferRequest, domain: String, base_salary: Money) -> Offer {
+    Offer {
         id: Uuid::new_v4(),
         title: body.title.clone(),
         link: body.link.clone(),
         city: body.city.clone(),
-        domain: body.domain.clone(),
-        salary: body.salary,
+        domain,
+        salary: body.salary.clone(),
+        base_salary: Some(base_salary),
         start_date: body.start_date,
         end_date: body.end_date,
         available: body.available,
+        seats: body.seats,
+        reserved_seats: 0,
+        deleted_at: None,
+        application_deadline: body.application_deadline,
+        company_id: body.company_id,
+        description: body.description.clone(),
+        skills: body.skills.clone(),
+        languages: body.languages.clone(),
+        remote_policy: body.remote_policy,
+        version: 1,
+    }
+}
+
+/// Monthly amount of a salary in the base currency of the exchange rates.
+async fn to_base_salary(state: &AppState, salary: &Money) -> Result<Money, DomainError> {
+    state.exchange_rates.rates().await?.to_base(salary)
+}
+
+/// Expresses the salary bounds of a filter in the base currency of the exchange rates.
+pub async fn salary_bounds_in_base_currency(
+    state: &AppState,
+    filter: OfferFilter,
+) -> Result<OfferFilter, DomainError> {
+    if filter.currency.is_none() {
+        return Ok(filter);
+    }
+    filter.in_base_currency(&state.exchange_rates.rates().await?)
+}
+
+/// Publishes an offer on the `zukmove.events` exchange; a missing broker is not an error.
+pub async fn publish_offer_event(state: &AppState, routing_key: &str, offer: &Offer) {
+    publish(state, routing_key, offer.id, offer).await;
+}
+
+/// Publishes one event per offer, all sent before any is awaited.
+pub async fn publish_offer_events(state: &AppState, routing_key: &str, offers: &[Offer]) {
+    futures::future::join_all(
+        offers
+            .iter()
+            .map(|offer| publish_offer_event(state, routing_key, offer)),
+    )
+    .await;
+}
+
+/// Header naming the person behind an update, recorded in the offer history.
+pub const AUTHOR_HEADER: &str = "X-User";
+
+pub fn author(req: &HttpRequest) -> Option<String> {
+    req.headers()
+        .get(AUTHOR_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|author| !author.is_empty())
+        .map(str::to_string)
+}
+
+/// Records a revision with the changed fields and publishes `offer.updated`,
+/// unless the update changed nothing.
+pub async fn record_offer_update(
+    state: &AppState,
+    before: &Offer,
+    after: &Offer,
+    author: Option<String>,
+) {
+    let Some(event) = OfferUpdatedEvent::diff(before, after) else {
+        return;
     };
+    // The offer is already written: a lost revision is logged rather than failing the update
+    let revision = OfferRevision::new(&event, author, state.clock.now());
+    if let Err(e) = state.revision_repo.save(&revision).await {
+        log::error!(
+            "Failed to record revision {} of offer {}: {}",
+            revision.version,
+            after.id,
+            e
+        );
+    }
+    publish(state, "offer.updated", after.id, &event).await;
+}
 
-    match state.offer_repo.save(&offer).await {
-        Ok(o) => {
-            // Publish offer.created event
-            if let Some(ref channel) = state.rabbitmq_channel {
-                if let Ok(payload) = serde_json::to_vec(&o) {
-                    let _ = channel
-                        .basic_publish(
-                            "zukmove.events",
-                            "offer.created",
-                            lapin::options::BasicPublishOptions::default(),
-                            &payload,
-                            lapin::BasicProperties::default()
-                                .with_content_type("application/json".into())
-                                .with_delivery_mode(2),
-                        )
-                        .await;
-                    log::info!("Published offer.created event for offer {}", o.id);
-                }
-            }
-            HttpResponse::Created().json(o)
-        }
-        Err(e) => domain_error_to_response(e),
+async fn publish<T: serde::Serialize>(state: &AppState, routing_key: &str, id: Uuid, event: &T) {
+    if let Some(ref channel) = state.rabbitmq_channel
+        && let Ok(payload) = serde_json::to_vec(event)
+    {
+        let _ = channel
+            .basic_publish(
+                "zukmove.events",
+                routing_key,
+                lapin::options::BasicPublishOptions::default(),
+                &payload,
+                lapin::BasicProperties::default()
+                    .with_content_type("application/json".into())
+                    .with_delivery_mode(2),
+            )
+            .await;
+        log::info!("Published {} event for offer {}", routing_key, id);
     }
 }
 
@@ -64,7 +180,7 @@ pub async fn create_offer(
         ("id" = Uuid, Path, description = "Offer ID")
     ),
     responses(
-        (status = 200, description = "Offer found", body = Offer),
+        (status = 200, description = "Offer found, with its version as ETag", body = Offer),
         (status = 404, description = "Offer not found or unavailable"),
         (status = 500, description = "Internal error")
     )
@@ -72,15 +188,16 @@ pub async fn create_offer(
 pub async fn get_offer(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
     let id = path.into_inner();
     match state.offer_repo.find_by_id(id).await {
-        Ok(o) => HttpResponse::Ok().json(o),
+        Ok(o) => versioned(&o, o.version),
         Err(e) => domain_error_to_response(e),
     }
 }
 
 #[derive(serde::Deserialize)]
-pub struct OfferQuery {
-    pub domain: Option<String>,
-    pub city: Option<String>,
+pub struct PageQuery {
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+    pub sort: Option<String>,
 }
 
 #[utoipa::path(
@@ -88,34 +205,112 @@ pub struct OfferQuery {
     path = "/offer",
     params(
         ("domain" = inline(Option<String>), Query, description = "Filter by domain"),
-        ("city" = inline(Option<String>), Query, description = "Filter by city")
+        ("city" = inline(Option<String>), Query, description = "Filter by city"),
+        ("min_salary" = inline(Option<f64>), Query, description = "Minimum monthly salary"),
+        ("max_salary" = inline(Option<f64>), Query, description = "Maximum monthly salary"),
+        ("currency" = inline(Option<String>), Query, description = "ISO 4217 currency of the salary bounds (default: base currency of the exchange rates)"),
+        ("start_after" = inline(Option<chrono::NaiveDate>), Query, description = "Internship starts on or after this date"),
+        ("end_before" = inline(Option<chrono::NaiveDate>), Query, description = "Internship ends on or before this date"),
+        ("text" = inline(Option<String>), Query, description = "Case-insensitive text contained in the title"),
+        ("company_id" = inline(Option<Uuid>), Query, description = "Offers of this company"),
+        ("skill" = inline(Option<String>), Query, description = "Skill required by the offer"),
+        ("language" = inline(Option<String>), Query, description = "Language required by the offer"),
+        ("language_level" = inline(Option<CefrLevel>), Query, description = "With language: offers requiring at most this CEFR level"),
+        ("remote_policy" = inline(Option<RemotePolicy>), Query, description = "on_site, hybrid or remote"),
+        ("limit" = inline(Option<u32>), Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = inline(Option<String>), Query, description = "next_cursor of the previous page"),
+        ("sort" = inline(Option<String>), Query, description = "start_date, title, city or salary; prefix with '-' for descending order")
     ),
     responses(
-        (status = 200, description = "List of offers", body = [Offer]),
-        (status = 400, description = "Missing query parameters"),
+        (status = 200, description = "Page of offers matching every criterion", body = Page<Offer>),
+        (status = 400, description = "Invalid filter or pagination parameters"),
         (status = 500, description = "Internal error")
     )
 )]
 pub async fn list_offers(
     state: web::Data<AppState>,
-    query: web::Query<OfferQuery>,
+    filter: web::Query<OfferFilter>,
+    query: web::Query<PageQuery>,
 ) -> HttpResponse {
-    if let Some(ref domain) = query.domain {
-        match state.offer_repo.find_by_domain(domain).await {
-            Ok(offers) => HttpResponse::Ok().json(offers),
-            Err(e) => domain_error_to_response(e),
-        }
-    } else if let Some(ref city) = query.city {
-        match state.offer_repo.find_by_city(city).await {
-            Ok(offers) => HttpResponse::Ok().json(offers),
-            Err(e) => domain_error_to_response(e),
-        }
-    } else {
-        match state.offer_repo.find_all().await {
-            Ok(offers) => HttpResponse::Ok().json(offers),
-            Err(e) => domain_error_to_response(e),
+    let filter = filter.into_inner();
+    let page = match PageRequest::parse::<Offer>(
+        query.limit,
+        query.cursor.as_deref(),
+        query.sort.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if let Err(e) = filter.validate() {
+        return domain_error_to_response(e);
+    }
+    let mut filter = match salary_bounds_in_base_currency(&state, filter).await {
+        Ok(filter) => filter,
+        Err(e) => return domain_error_to_response(e),
+    };
+    // An alias selects the same offers as the domain's name
+    if let Some(ref domain) = filter.domain {
+        match load_taxonomy(&state).await {
+            Ok(taxonomy) => {
+                if let Some(resolved) = taxonomy.resolve(domain) {
+                    filter.domain = Some(resolved.name.clone());
+                }
+            }
+            Err(e) => return domain_error_to_response(e),
         }
     }
+
+    match state
+        .offer_repo
+        .search(&filter, OfferVisibility::Available, &page)
+        .await
+    {
+        Ok(offers) => HttpResponse::Ok().json(offers),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub struct TextSearchQuery {
+    pub q: String,
+    pub limit: Option<u32>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/offer/search",
+    params(
+        ("q" = String, Query, description = "Words to look for in offer titles"),
+        ("limit" = inline(Option<u32>), Query, description = "Maximum number of results (default 20, max 100)")
+    ),
+    responses(
+        (status = 200, description = "Available offers ranked by relevance", body = [Offer]),
+        (status = 400, description = "Empty query or invalid limit"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn search_offers(
+    state: web::Data<AppState>,
+    query: web::Query<TextSearchQuery>,
+) -> HttpResponse {
+    let q = query.q.trim();
+    if q.is_empty() {
+        return HttpResponse::BadRequest().json(serde_json::json!({
+            "error": "Query parameter 'q' must not be empty"
+        }));
+    }
+    let limit = query.limit.unwrap_or(PageRequest::DEFAULT_LIMIT);
+    if limit == 0 || limit > PageRequest::MAX_LIMIT {
+        return domain_error_to_response(DomainError::ValidationError(format!(
+            "limit must be between 1 and {}",
+            PageRequest::MAX_LIMIT
+        )));
+    }
+
+    match state.offer_repo.search_text(q, limit).await {
+        Ok(offers) => HttpResponse::Ok().json(offers),
+        Err(e) => domain_error_to_response(e),
+    }
 }
 
 #[utoipa::path(
@@ -123,48 +318,141 @@ pub async fn list_offers(
     path = "/offer/{id}",
     request_body = UpdateOfferRequest,
     params(
-        ("id" = Uuid, Path, description = "Offer ID")
+        ("id" = Uuid, Path, description = "Offer ID"),
+        ("If-Match" = inline(Option<String>), Header, description = "ETag of the offer as last read; the update is refused if it changed since"),
+        ("X-User" = inline(Option<String>), Header, description = "Author of the update, recorded in the offer history")
     ),
     responses(
-        (status = 200, description = "Offer updated", body = Offer),
+        (status = 200, description = "Offer updated (records a revision and publishes offer.updated when a field changed), with its new ETag", body = Offer),
+        (status = 400, description = "Domain missing from the catalog or malformed If-Match"),
         (status = 404, description = "Offer not found"),
+        (status = 409, description = "Offer archived (restore it first), or fewer seats than already reserved"),
+        (status = 412, description = "Offer modified since the ETag in If-Match, or by a concurrent update"),
+        (status = 422, description = "Invalid fields, all listed in `fields`", body = ValidationFailure),
         (status = 500, description = "Internal error")
     )
 )]
 pub async fn update_offer(
     state: web::Data<AppState>,
+    req: HttpRequest,
     path: web::Path<Uuid>,
     body: web::Json<UpdateOfferRequest>,
 ) -> HttpResponse {
     let id = path.into_inner();
+    let condition = match if_match(&req) {
+        Ok(condition) => condition,
+        Err(e) => return domain_error_to_response(e),
+    };
 
-    // Retrieve existing — note: find_by_id enforces available=true,
-    // but for update we need to find even unavailable offers.
-    // We'll try to find it and if not found treat as 404.
-    let existing = match state.offer_repo.find_by_id(id).await {
+    // Hidden offers can be updated too, e.g. to make them available again
+    let existing = match state.offer_repo.find_by_id_including_unavailable(id).await {
         Ok(o) => o,
-        Err(DomainError::NotFound(_)) => {
-            return HttpResponse::NotFound().json(serde_json::json!({
-                "error": format!("Offer with id {} not found", id)
-            }));
-        }
         Err(e) => return domain_error_to_response(e),
     };
+    if existing.is_archived() {
+        return domain_error_to_response(DomainError::Conflict(format!(
+            "Offer with id {} is archived: restore it before updating it",
+            id
+        )));
+    }
+    if let Some(condition) = condition
+        && let Err(e) = condition.check(existing.version, &format!("Offer with id {}", id))
+    {
+        return domain_error_to_response(e);
+    }
+    if let Err(e) = body.validate_against(&existing) {
+        return domain_error_to_response(e);
+    }
+    let previous = existing.clone();
+
+    let domain = match body.domain {
+        Some(ref domain) => match load_taxonomy(&state)
+            .await
+            .and_then(|t| t.canonical_name_for_update(domain, &existing.domain))
+        {
+            Ok(domain) => domain,
+            Err(e) => return domain_error_to_response(e),
+        },
+        None => existing.domain,
+    };
+    if let Some(Some(company_id)) = body.company_id
+        && let Err(e) = check_company_exists(&state, company_id).await
+    {
+        return domain_error_to_response(e);
+    }
+    let (salary, base_salary) = match body.salary {
+        Some(ref salary) => match to_base_salary(&state, salary).await {
+            Ok(base) => (salary.clone(), Some(base)),
+            Err(e) => return domain_error_to_response(e),
+        },
+        None => (existing.salary, existing.base_salary),
+    };
 
-    let updated = Offer {
+    let mut updated = Offer {
         id: existing.id,
         title: body.title.clone().unwrap_or(existing.title),
         link: body.link.clone().unwrap_or(existing.link),
         city: body.city.clone().unwrap_or(existing.city),
-        domain: body.domain.clone().unwrap_or(existing.domain),
-        salary: body.salary.unwrap_or(existing.salary),
+        domain,
+        salary,
+        base_salary,
         start_date: body.start_date.unwrap_or(existing.start_date),
         end_date: body.end_date.unwrap_or(existing.end_date),
-        available: body.available.unwrap_or(existing.available),
+        available: existing.available,
+        seats: existing.seats,
+        reserved_seats: existing.reserved_seats,
+        deleted_at: existing.deleted_at,
+        application_deadline: body
+            .application_deadline
+            .unwrap_or(existing.application_deadline),
+        company_id: body.company_id.unwrap_or(existing.company_id),
+        description: body.description.clone().unwrap_or(existing.description),
+        skills: body.skills.clone().unwrap_or(existing.skills),
+        languages: body.languages.clone().unwrap_or(existing.languages),
+        remote_policy: body.remote_policy.unwrap_or(existing.remote_policy),
+        // The write only succeeds if nobody updated the offer since it was read above
+        version: existing.version,
     };
+    // Reservations are versioned writes too, so the seats checked here cannot change before the update
+    if let Some(seats) = body.seats
+        && let Err(e) = updated.resize(seats)
+    {
+        return domain_error_to_response(e);
+    }
+    if let Some(available) = body.available {
+        // A full offer stays hidden
+        updated.available = available && updated.remaining_seats() > 0;
+    }
 
     match state.offer_repo.update(&updated).await {
-        Ok(o) => HttpResponse::Ok().json(o),
+        Ok(o) => {
+            record_offer_update(&state, &previous, &o, author(&req)).await;
+            versioned(&o, o.version)
+        }
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/offer/{id}/history",
+    params(
+        ("id" = Uuid, Path, description = "Offer ID")
+    ),
+    responses(
+        (status = 200, description = "Revisions of the offer, oldest first, even once hidden or archived", body = [OfferRevision]),
+        (status = 404, description = "Offer not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_offer_history(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    let id = path.into_inner();
+    // Applicants keep following an offer after it is filled or archived
+    if let Err(e) = state.offer_repo.find_by_id_including_unavailable(id).await {
+        return domain_error_to_response(e);
+    }
+    match state.revision_repo.find_by_offer(id).await {
+        Ok(revisions) => HttpResponse::Ok().json(revisions),
         Err(e) => domain_error_to_response(e),
     }
 }
@@ -176,20 +464,86 @@ pub async fn update_offer(
         ("id" = Uuid, Path, description = "Offer ID")
     ),
     responses(
-        (status = 204, description = "Offer deleted"),
-        (status = 404, description = "Offer not found"),
+        (status = 204, description = "Offer archived (publishes offer.deleted)"),
+        (status = 404, description = "Offer not found or already deleted"),
         (status = 500, description = "Internal error")
     )
 )]
 pub async fn delete_offer(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
     let id = path.into_inner();
-    match state.offer_repo.delete(id).await {
-        Ok(()) => HttpResponse::NoContent().finish(),
+    // Soft delete: internships keep pointing at an offer that admins can still read
+    match state.offer_repo.archive(id, chrono::Utc::now()).await {
+        Ok(o) => {
+            publish_offer_event(&state, "offer.deleted", &o).await;
+            HttpResponse::NoContent().finish()
+        }
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/offer/{id}/reserve",
+    params(
+        ("id" = Uuid, Path, description = "Offer ID")
+    ),
+    responses(
+        (status = 200, description = "Seat reserved", body = Offer),
+        (status = 404, description = "Offer not found"),
+        (status = 409, description = "No seat left"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn reserve_seat(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    let id = path.into_inner();
+    match state.offer_repo.reserve_seat(id).await {
+        Ok(o) => HttpResponse::Ok().json(o),
         Err(e) => domain_error_to_response(e),
     }
 }
 
-fn domain_error_to_response(err: DomainError) -> HttpResponse {
+#[utoipa::path(
+    post,
+    path = "/offer/{id}/release",
+    params(
+        ("id" = Uuid, Path, description = "Offer ID")
+    ),
+    responses(
+        (status = 200, description = "Seat released", body = Offer),
+        (status = 404, description = "Offer not found"),
+        (status = 409, description = "No reserved seat to release"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn release_seat(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    let id = path.into_inner();
+    match state.offer_repo.release_seat(id).await {
+        Ok(o) => HttpResponse::Ok().json(o),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+/// Reads the `If-Match` header of a conditional update, if any.
+pub fn if_match(req: &HttpRequest) -> Result<Option<IfMatch>, DomainError> {
+    req.headers()
+        .get(header::IF_MATCH)
+        .map(|value| {
+            value
+                .to_str()
+                .map_err(|_| DomainError::ValidationError("Invalid If-Match header".to_string()))
+                .and_then(IfMatch::parse)
+        })
+        .transpose()
+}
+
+/// 200 response carrying the version of the resource as its `ETag`.
+pub fn versioned<T: serde::Serialize>(body: &T, version: u64) -> HttpResponse {
+    HttpResponse::Ok()
+        .insert_header((header::ETAG, etag(version)))
+        .json(body)
+}
+
+pub fn domain_error_to_response(err: DomainError) -> HttpResponse {
     match err {
         DomainError::NotFound(msg) => HttpResponse::NotFound().json(serde_json::json!({
             "error": msg
@@ -197,6 +551,17 @@ fn domain_error_to_response(err: DomainError) -> HttpResponse {
         DomainError::ValidationError(msg) => HttpResponse::BadRequest().json(serde_json::json!({
             "error": msg
         })),
+        DomainError::InvalidFields(fields) => {
+            HttpResponse::UnprocessableEntity().json(ValidationFailure::new(fields))
+        }
+        DomainError::Conflict(msg) => HttpResponse::Conflict().json(serde_json::json!({
+            "error": msg
+        })),
+        DomainError::PreconditionFailed(msg) => {
+            HttpResponse::PreconditionFailed().json(serde_json::json!({
+                "error": msg
+            }))
+        }
         DomainError::InfrastructureError(msg) => {
             log::error!("Infrastructure error: {}", msg);
             HttpResponse::InternalServerError().json(serde_json::json!({
@@ -0,0 +1,32 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use zukmove_core::domain::ports::Clock;
+
+/// Clock for testing: stays on the given day until moved with `set`.
+pub struct FixedClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    pub fn on(day: NaiveDate) -> Self {
+        Self {
+            now: Mutex::new(Self::noon(day)),
+        }
+    }
+
+    pub fn set(&self, day: NaiveDate) {
+        *self.now.lock().unwrap() = Self::noon(day);
+    }
+
+    fn noon(day: NaiveDate) -> DateTime<Utc> {
+        day.and_hms_opt(12, 0, 0).unwrap().and_utc()
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap()
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::company::Company;
+use zukmove_core::domain::ports::{CompanyRepository, DomainError};
+
+/// In-memory implementation of CompanyRepository for testing.
+pub struct InMemoryCompanyRepository {
+    store: Mutex<HashMap<Uuid, Company>>,
+}
+
+impl InMemoryCompanyRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl CompanyRepository for InMemoryCompanyRepository {
+    async fn save(&self, company: &Company) -> Result<Company, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        store.insert(company.id, company.clone());
+        Ok(company.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Company, DomainError> {
+        let store = self.store.lock().unwrap();
+        store
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("Company with id {} not found", id)))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Company>, DomainError> {
+        let store = self.store.lock().unwrap();
+        let mut companies: Vec<Company> = store.values().cloned().collect();
+        companies.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(companies)
+    }
+
+    async fn update(&self, company: &Company) -> Result<Company, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        if !store.contains_key(&company.id) {
+            return Err(DomainError::NotFound(format!(
+                "Company with id {} not found",
+                company.id
+            )));
+        }
+        store.insert(company.id, company.clone());
+        Ok(company.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let mut store = self.store.lock().unwrap();
+        store
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::NotFound(format!("Company with id {} not found", id)))
+    }
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::domain::Domain;
+use zukmove_core::domain::ports::{DomainError, DomainRepository};
+
+/// In-memory implementation of DomainRepository for testing.
+pub struct InMemoryDomainRepository {
+    store: Mutex<HashMap<Uuid, Domain>>,
+}
+
+impl InMemoryDomainRepository {
+    pub fn with_domains(domains: Vec<Domain>) -> Self {
+        Self {
+            store: Mutex::new(domains.into_iter().map(|d| (d.id, d)).collect()),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainRepository for InMemoryDomainRepository {
+    async fn save(&self, domain: &Domain) -> Result<Domain, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        store.insert(domain.id, domain.clone());
+        Ok(domain.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Domain, DomainError> {
+        let store = self.store.lock().unwrap();
+        store
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("Domain with id {} not found", id)))
+    }
+
+    async fn find_all(&self) -> Result<Vec<Domain>, DomainError> {
+        let store = self.store.lock().unwrap();
+        let mut domains: Vec<Domain> = store.values().cloned().collect();
+        domains.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(domains)
+    }
+
+    async fn update(&self, domain: &Domain) -> Result<Domain, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        if !store.contains_key(&domain.id) {
+            return Err(DomainError::NotFound(format!(
+                "Domain with id {} not found",
+                domain.id
+            )));
+        }
+        store.insert(domain.id, domain.clone());
+        Ok(domain.clone())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let mut store = self.store.lock().unwrap();
+        store
+            .remove(&id)
+            .map(|_| ())
+            .ok_or_else(|| DomainError::NotFound(format!("Domain with id {} not found", id)))
+    }
+}
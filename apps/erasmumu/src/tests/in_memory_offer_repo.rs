@@ -2,9 +2,11 @@ use std::collections::HashMap;
 use std::sync::Mutex;
 
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::offer::Offer;
+use zukmove_core::domain::entities::offer::{Offer, OfferFilter, OfferVisibility};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 use zukmove_core::domain::ports::{DomainError, OfferRepository};
 
 /// In-memory implementation of OfferRepository for testing.
@@ -28,6 +30,12 @@ impl OfferRepository for InMemoryOfferRepository {
         Ok(offer.clone())
     }
 
+    async fn save_many(&self, offers: &[Offer]) -> Result<(), DomainError> {
+        let mut store = self.store.lock().unwrap();
+        store.extend(offers.iter().map(|o| (o.id, o.clone())));
+        Ok(())
+    }
+
     async fn find_by_id(&self, id: Uuid) -> Result<Offer, DomainError> {
         let store = self.store.lock().unwrap();
         let offer = store
@@ -35,8 +43,8 @@ impl OfferRepository for InMemoryOfferRepository {
             .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?
             .clone();
 
-        // Business rule: filter unavailable offers
-        if !offer.available {
+        // Business rule: filter unavailable and archived offers
+        if !offer.available || offer.is_archived() {
             return Err(DomainError::NotFound(format!(
                 "Offer with id {} is not available",
                 id
@@ -45,46 +53,153 @@ impl OfferRepository for InMemoryOfferRepository {
         Ok(offer)
     }
 
-    async fn find_by_domain(&self, domain: &str) -> Result<Vec<Offer>, DomainError> {
+    async fn find_by_id_including_unavailable(&self, id: Uuid) -> Result<Offer, DomainError> {
         let store = self.store.lock().unwrap();
-        Ok(store
-            .values()
-            .filter(|o| o.domain == domain && o.available)
+        store
+            .get(&id)
             .cloned()
-            .collect())
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))
     }
 
-    async fn find_by_city(&self, city: &str) -> Result<Vec<Offer>, DomainError> {
+    async fn search(
+        &self,
+        filter: &OfferFilter,
+        visibility: OfferVisibility,
+        page: &PageRequest,
+    ) -> Result<Page<Offer>, DomainError> {
         let store = self.store.lock().unwrap();
-        Ok(store
-            .values()
-            .filter(|o| o.city == city && o.available)
-            .cloned()
-            .collect())
+        Ok(Page::paginate(
+            store
+                .values()
+                .filter(|o| visibility.includes(o) && filter.matches_criteria(o))
+                .cloned(),
+            page,
+        ))
     }
 
-    async fn find_all(&self) -> Result<Vec<Offer>, DomainError> {
+    async fn search_text(&self, query: &str, limit: u32) -> Result<Vec<Offer>, DomainError> {
+        let terms = tokenize(query);
         let store = self.store.lock().unwrap();
-        Ok(store.values().filter(|o| o.available).cloned().collect())
+        // Rank by number of query terms found in the title, like a text score
+        let mut scored: Vec<(usize, Offer)> = store
+            .values()
+            .filter(|o| o.available && !o.is_archived())
+            .map(|o| {
+                let words = tokenize(&o.title);
+                (
+                    terms.iter().filter(|t| words.contains(t)).count(),
+                    o.clone(),
+                )
+            })
+            .filter(|(score, _)| *score > 0)
+            .collect();
+        scored.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.title.cmp(&b.1.title)));
+        Ok(scored
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, o)| o)
+            .collect())
     }
 
     async fn update(&self, offer: &Offer) -> Result<Offer, DomainError> {
         let mut store = self.store.lock().unwrap();
-        if !store.contains_key(&offer.id) {
-            return Err(DomainError::NotFound(format!(
-                "Offer with id {} not found",
-                offer.id
+        let current = store.get(&offer.id).ok_or_else(|| {
+            DomainError::NotFound(format!("Offer with id {} not found", offer.id))
+        })?;
+        if current.version != offer.version {
+            return Err(DomainError::PreconditionFailed(format!(
+                "Offer with id {} was modified since version {}",
+                offer.id, offer.version
             )));
         }
-        store.insert(offer.id, offer.clone());
+        let updated = Offer {
+            version: offer.version + 1,
+            ..offer.clone()
+        };
+        store.insert(offer.id, updated.clone());
+        Ok(updated)
+    }
+
+    async fn archive(&self, id: Uuid, deleted_at: DateTime<Utc>) -> Result<Offer, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        let offer = store
+            .get_mut(&id)
+            .filter(|o| !o.is_archived())
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
+        offer.deleted_at = Some(deleted_at);
+        offer.version += 1;
         Ok(offer.clone())
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+    async fn restore(&self, id: Uuid) -> Result<Offer, DomainError> {
         let mut store = self.store.lock().unwrap();
-        store
-            .remove(&id)
+        let offer = store
+            .get_mut(&id)
             .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
-        Ok(())
+        if !offer.is_archived() {
+            return Err(DomainError::Conflict(format!(
+                "Offer with id {} is not archived",
+                id
+            )));
+        }
+        offer.deleted_at = None;
+        offer.version += 1;
+        Ok(offer.clone())
+    }
+
+    async fn reserve_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        let offer = store
+            .get_mut(&id)
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
+        if offer.is_archived() || offer.remaining_seats() == 0 {
+            return Err(DomainError::Conflict(format!(
+                "Offer with id {} has no seats left",
+                id
+            )));
+        }
+        offer.reserved_seats += 1;
+        offer.available = offer.available && offer.remaining_seats() > 0;
+        offer.version += 1;
+        Ok(offer.clone())
     }
+
+    async fn release_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        let offer = store
+            .get_mut(&id)
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
+        if offer.reserved_seats == 0 {
+            return Err(DomainError::Conflict(format!(
+                "Offer with id {} has no reserved seat",
+                id
+            )));
+        }
+        if offer.remaining_seats() == 0 {
+            offer.available = true;
+        }
+        offer.reserved_seats -= 1;
+        offer.version += 1;
+        Ok(offer.clone())
+    }
+
+    async fn expire_due(&self, today: NaiveDate) -> Result<Vec<Offer>, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        Ok(store
+            .values_mut()
+            .filter(|o| OfferVisibility::Available.includes(o) && o.is_expired(today))
+            .map(|o| {
+                o.available = false;
+                o.version += 1;
+                o.clone()
+            })
+            .collect())
+    }
+}
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+        .collect()
 }
@@ -0,0 +1,41 @@
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::offer::OfferRevision;
+use zukmove_core::domain::ports::{DomainError, OfferRevisionRepository};
+
+/// In-memory implementation of OfferRevisionRepository for testing.
+pub struct InMemoryOfferRevisionRepository {
+    revisions: Mutex<Vec<OfferRevision>>,
+}
+
+impl InMemoryOfferRevisionRepository {
+    pub fn new() -> Self {
+        Self {
+            revisions: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl OfferRevisionRepository for InMemoryOfferRevisionRepository {
+    async fn save(&self, revision: &OfferRevision) -> Result<OfferRevision, DomainError> {
+        self.revisions.lock().unwrap().push(revision.clone());
+        Ok(revision.clone())
+    }
+
+    async fn find_by_offer(&self, offer_id: Uuid) -> Result<Vec<OfferRevision>, DomainError> {
+        let mut revisions: Vec<OfferRevision> = self
+            .revisions
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.offer_id == offer_id)
+            .cloned()
+            .collect();
+        revisions.sort_by_key(|r| (r.version, r.changed_at));
+        Ok(revisions)
+    }
+}
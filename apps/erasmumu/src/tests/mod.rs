@@ -1,19 +1,81 @@
+mod fixed_clock;
+mod in_memory_company_repo;
+mod in_memory_domain_repo;
 mod in_memory_offer_repo;
+mod in_memory_offer_revision_repo;
+
+use std::sync::Arc;
 
 use actix_web::{App, test, web};
+use chrono::NaiveDate;
 use serde_json::json;
 
 use crate::AppState;
+use crate::adapters::static_exchange_rates::StaticExchangeRateProvider;
+use crate::expiry;
 use crate::routes;
+use fixed_clock::FixedClock;
+use in_memory_company_repo::InMemoryCompanyRepository;
+use in_memory_domain_repo::InMemoryDomainRepository;
 use in_memory_offer_repo::InMemoryOfferRepository;
-use zukmove_core::domain::entities::offer::Offer;
+use in_memory_offer_revision_repo::InMemoryOfferRevisionRepository;
+use zukmove_core::domain::entities::company::Company;
+use zukmove_core::domain::entities::domain::Domain;
+use zukmove_core::domain::entities::money::{ExchangeRates, Money, SalaryPeriod};
+use zukmove_core::domain::entities::offer::{Offer, OfferRevision, RemotePolicy};
+use zukmove_core::domain::pagination::Page;
+use zukmove_core::domain::validation::ValidationFailure;
+
+/// Catalog used by every test: IT (with aliases and a Data Science sub-domain),
+/// Life Science and Business.
+fn test_domains() -> Vec<Domain> {
+    let it = Domain {
+        id: uuid::Uuid::new_v4(),
+        name: "IT".to_string(),
+        parent_id: None,
+        aliases: vec!["Informatique".to_string(), "Computer Science".to_string()],
+    };
+    let data_science = Domain {
+        id: uuid::Uuid::new_v4(),
+        name: "Data Science".to_string(),
+        parent_id: Some(it.id),
+        aliases: vec![],
+    };
+    let others = ["Life Science", "Business"].map(|name| Domain {
+        id: uuid::Uuid::new_v4(),
+        name: name.to_string(),
+        parent_id: None,
+        aliases: vec![],
+    });
+    [it, data_science].into_iter().chain(others).collect()
+}
+
+fn test_rates() -> ExchangeRates {
+    ExchangeRates::from_decimal("EUR", [("GBP".to_string(), 0.8)]).unwrap()
+}
 
 fn test_app_state() -> web::Data<AppState> {
+    // Before the start of every offer created by the tests
+    test_app_state_with_clock(Arc::new(FixedClock::on(date("2026-05-01"))))
+}
+
+fn test_app_state_with_clock(clock: Arc<FixedClock>) -> web::Data<AppState> {
     web::Data::new(AppState {
         offer_repo: Box::new(InMemoryOfferRepository::new()),
+        revision_repo: Box::new(InMemoryOfferRevisionRepository::new()),
+        domain_repo: Box::new(InMemoryDomainRepository::with_domains(test_domains())),
+        company_repo: Box::new(InMemoryCompanyRepository::new()),
+        clock,
+        exchange_rates: Box::new(StaticExchangeRateProvider::new(test_rates())),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
     })
 }
 
+fn date(value: &str) -> NaiveDate {
+    value.parse().unwrap()
+}
+
 fn test_app(
     state: web::Data<AppState>,
 ) -> App<
@@ -29,9 +91,59 @@ fn test_app(
         .app_data(state)
         .route("/offer", web::post().to(routes::offer::create_offer))
         .route("/offer", web::get().to(routes::offer::list_offers))
+        .route("/offer/search", web::get().to(routes::offer::search_offers))
+        .route("/offer/import", web::post().to(routes::bulk::import_offers))
+        .route("/offer/export", web::get().to(routes::bulk::export_offers))
         .route("/offer/{id}", web::get().to(routes::offer::get_offer))
         .route("/offer/{id}", web::put().to(routes::offer::update_offer))
         .route("/offer/{id}", web::delete().to(routes::offer::delete_offer))
+        .route(
+            "/offer/{id}/history",
+            web::get().to(routes::offer::get_offer_history),
+        )
+        .route(
+            "/offer/{id}/reserve",
+            web::post().to(routes::offer::reserve_seat),
+        )
+        .route(
+            "/offer/{id}/release",
+            web::post().to(routes::offer::release_seat),
+        )
+        .route(
+            "/admin/offer",
+            web::get().to(routes::admin::list_all_offers),
+        )
+        .route(
+            "/admin/offer/{id}",
+            web::get().to(routes::admin::get_any_offer),
+        )
+        .route(
+            "/admin/offer/{id}/reactivate",
+            web::post().to(routes::admin::reactivate_offer),
+        )
+        .route(
+            "/admin/offer/{id}/restore",
+            web::post().to(routes::admin::restore_offer),
+        )
+        .route("/domain", web::get().to(routes::domain::list_domains))
+        .route("/domain", web::post().to(routes::domain::create_domain))
+        .route("/domain/{id}", web::get().to(routes::domain::get_domain))
+        .route("/domain/{id}", web::put().to(routes::domain::update_domain))
+        .route(
+            "/domain/{id}",
+            web::delete().to(routes::domain::delete_domain),
+        )
+        .route("/company", web::get().to(routes::company::list_companies))
+        .route("/company", web::post().to(routes::company::create_company))
+        .route("/company/{id}", web::get().to(routes::company::get_company))
+        .route(
+            "/company/{id}",
+            web::put().to(routes::company::update_company),
+        )
+        .route(
+            "/company/{id}",
+            web::delete().to(routes::company::delete_company),
+        )
 }
 
 // ─── POST /offer ───
@@ -187,8 +299,9 @@ async fn test_list_offers_by_domain() {
 
     assert_eq!(resp.status(), 200);
 
-    let body: Vec<Offer> = test::read_body_json(resp).await;
-    assert_eq!(body.len(), 2);
+    let body: Page<Offer> = test::read_body_json(resp).await;
+    assert_eq!(body.items.len(), 2);
+    assert!(body.next_cursor.is_none());
 }
 
 // ─── GET /offer?city= ───
@@ -223,8 +336,9 @@ async fn test_list_offers_by_city() {
 
     assert_eq!(resp.status(), 200);
 
-    let body: Vec<Offer> = test::read_body_json(resp).await;
-    assert_eq!(body.len(), 2);
+    let body: Page<Offer> = test::read_body_json(resp).await;
+    assert_eq!(body.items.len(), 2);
+    assert!(body.next_cursor.is_none());
 }
 
 // ─── PUT /offer/{id} ───
@@ -266,6 +380,213 @@ async fn test_update_offer() {
     assert_eq!(body.city, "Paris"); // unchanged
 }
 
+#[actix_web::test]
+async fn test_stale_offer_updates_are_refused_with_412() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage Concurrent",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "IT",
+        "salary": 1000.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": true,
+        "seats": 2
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let etag = resp
+        .headers()
+        .get("ETag")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+    assert_eq!(etag, "\"1\"");
+
+    // First admin saves with the ETag they read
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .insert_header(("If-Match", etag.as_str()))
+        .set_json(json!({ "title": "Stage Relu" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(resp.headers().get("ETag").unwrap(), "\"2\"");
+
+    // Second admin read the same version: their update would overwrite the first one
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .insert_header(("If-Match", etag.as_str()))
+        .set_json(json!({ "city": "Lyon" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 412);
+
+    // A reservation is a write too
+    let req = test::TestRequest::post()
+        .uri(&format!("/offer/{}/reserve", created.id))
+        .to_request();
+    let reserved: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(reserved.version, 3);
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .insert_header(("If-Match", "\"2\""))
+        .set_json(json!({ "seats": 1 }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 412);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .insert_header(("If-Match", "3"))
+        .set_json(json!({ "seats": 1 }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    // Without If-Match the update applies to the version just read
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .set_json(json!({ "city": "Lyon" }))
+        .to_request();
+    let updated: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(updated.title, "Stage Relu");
+    assert_eq!(updated.reserved_seats, 1);
+    assert_eq!(updated.version, 4);
+}
+
+#[actix_web::test]
+async fn test_update_cannot_drop_seats_below_reservations() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(json!({
+            "title": "Stage",
+            "link": "http://example.com",
+            "city": "Paris",
+            "domain": "IT",
+            "salary": 1000.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true,
+            "seats": 3
+        }))
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+    for _ in 0..2 {
+        let req = test::TestRequest::post()
+            .uri(&format!("/offer/{}/reserve", created.id))
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
+    let update = |body: serde_json::Value| {
+        test::TestRequest::put()
+            .uri(&format!("/offer/{}", created.id))
+            .set_json(body)
+            .to_request()
+    };
+    let resp = test::call_service(&app, update(json!({ "seats": 1 }))).await;
+    assert_eq!(resp.status(), 409);
+
+    // Down to the reserved seats: the offer is full and hidden
+    let full: Offer = test::call_and_read_body_json(&app, update(json!({ "seats": 2 }))).await;
+    assert_eq!(full.reserved_seats, 2);
+    assert!(!full.available);
+    let still_full: Offer =
+        test::call_and_read_body_json(&app, update(json!({ "available": true }))).await;
+    assert!(!still_full.available);
+
+    // A new seat re-opens it
+    let reopened: Offer = test::call_and_read_body_json(&app, update(json!({ "seats": 3 }))).await;
+    assert_eq!(reopened.remaining_seats(), 1);
+    assert!(reopened.available);
+}
+
+#[actix_web::test]
+async fn test_offer_history_records_every_update() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage Backend",
+        "link": "http://example.com",
+        "city": "Berlin",
+        "domain": "IT",
+        "salary": 1500.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": true
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .insert_header(("X-User", "rh@acme.de"))
+        .set_json(json!({ "salary": 1100.0 }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 200);
+
+    // An update that changes nothing leaves no revision
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .set_json(json!({ "city": "Berlin" }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 200);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .set_json(json!({ "title": "Stage Backend Rust" }))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 200);
+
+    // The history stays readable once the offer is archived
+    let req = test::TestRequest::delete()
+        .uri(&format!("/offer/{}", created.id))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 204);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}/history", created.id))
+        .to_request();
+    let history: Vec<OfferRevision> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(history.len(), 2);
+
+    let lowered = &history[0];
+    assert_eq!(lowered.author.as_deref(), Some("rh@acme.de"));
+    assert_eq!(lowered.version, 2);
+    assert_eq!(lowered.changes["salary"].from["amount"], 150_000);
+    assert_eq!(lowered.changes["salary"].to["amount"], 110_000);
+    assert!(lowered.changes.contains_key("base_salary"));
+    assert!(!lowered.changes.contains_key("version"));
+
+    assert_eq!(history[1].author, None);
+    assert_eq!(history[1].changes.keys().collect::<Vec<_>>(), vec!["title"]);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}/history", uuid::Uuid::new_v4()))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 404);
+}
+
 // ─── DELETE /offer/{id} ───
 
 #[actix_web::test]
@@ -307,6 +628,92 @@ async fn test_delete_offer() {
     assert_eq!(resp.status(), 404);
 }
 
+#[actix_web::test]
+async fn test_deleted_offer_is_archived_and_can_be_restored() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage archivé",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "IT",
+        "salary": 1000.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": true
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/offer/{}", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 204);
+
+    // Still readable by admins, and listed as archived only
+    let req = test::TestRequest::get()
+        .uri(&format!("/admin/offer/{}", created.id))
+        .to_request();
+    let body: Offer = test::call_and_read_body_json(&app, req).await;
+    assert!(body.deleted_at.is_some());
+
+    let req = test::TestRequest::get().uri("/offer").to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert!(body.items.is_empty());
+
+    let req = test::TestRequest::get()
+        .uri("/admin/offer?visibility=archived")
+        .to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 1);
+
+    // Deleting twice is a 404, like any missing offer
+    let req = test::TestRequest::delete()
+        .uri(&format!("/offer/{}", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+
+    // An archived offer must be restored before it can be edited
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .set_json(json!({ "title": "Stage restauré" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/offer/{}/restore", created.id))
+        .to_request();
+    let body: Offer = test::call_and_read_body_json(&app, req).await;
+    assert!(body.deleted_at.is_none());
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .set_json(json!({ "title": "Stage restauré" }))
+        .to_request();
+    let body: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.title, "Stage restauré");
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    // Restoring an offer that is not archived is refused
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/offer/{}/restore", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+}
+
 #[actix_web::test]
 async fn test_delete_offer_not_found() {
     let state = test_app_state();
@@ -349,6 +756,1073 @@ async fn test_list_offers_without_filter_returns_all_offers() {
 
     assert_eq!(resp.status(), 200);
 
-    let body: Vec<Offer> = test::read_body_json(resp).await;
-    assert_eq!(body.len(), 1);
+    let body: Page<Offer> = test::read_body_json(resp).await;
+    assert_eq!(body.items.len(), 1);
+}
+
+// ─── GET /offer with combined criteria ───
+
+#[actix_web::test]
+async fn test_list_offers_combines_all_criteria() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    for (title, city, domain, salary, start_date) in [
+        ("Backend Rust", "Berlin", "IT", 1400.0, "2026-09-01"),
+        ("Backend Go", "Paris", "IT", 1400.0, "2026-09-01"),
+        ("Frontend", "Berlin", "IT", 1000.0, "2026-09-01"),
+        ("Data", "Berlin", "IT", 1500.0, "2026-03-01"),
+        ("Lab", "Berlin", "life science", 1500.0, "2026-09-01"),
+    ] {
+        let payload = json!({
+            "title": title,
+            "link": "http://example.com",
+            "city": city,
+            "domain": domain,
+            "salary": salary,
+            "start_date": start_date,
+            "end_date": "2027-03-01",
+            "available": true
+        });
+        let req = test::TestRequest::post()
+            .uri("/offer")
+            .set_json(&payload)
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/offer?domain=IT&city=Berlin&min_salary=1200&start_after=2026-09-01")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let body: Page<Offer> = test::read_body_json(resp).await;
+    assert_eq!(body.items.len(), 1);
+    assert_eq!(body.items[0].title, "Backend Rust");
+
+    let req = test::TestRequest::get()
+        .uri("/offer?text=backend")
+        .to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 2);
+}
+
+#[actix_web::test]
+async fn test_list_offers_with_inverted_salary_range_returns_400() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri("/offer?min_salary=2000&max_salary=1000")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 400);
+}
+
+// ─── GET /offer/search?q= ───
+
+#[actix_web::test]
+async fn test_search_offers_ranks_by_relevance() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    for title in ["Stage Rust", "Stage Backend Rust", "Stage Frontend"] {
+        let payload = json!({
+            "title": title,
+            "link": "http://example.com",
+            "city": "Paris",
+            "domain": "IT",
+            "salary": 1000.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true
+        });
+        let req = test::TestRequest::post()
+            .uri("/offer")
+            .set_json(&payload)
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/offer/search?q=rust%20backend")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let body: Vec<Offer> = test::read_body_json(resp).await;
+    let titles: Vec<&str> = body.iter().map(|o| o.title.as_str()).collect();
+    assert_eq!(titles, vec!["Stage Backend Rust", "Stage Rust"]);
+}
+
+#[actix_web::test]
+async fn test_search_offers_with_empty_query_returns_400() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri("/offer/search?q=%20")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 400);
+}
+
+// ─── GET /offer?limit=&cursor=&sort= ───
+
+#[actix_web::test]
+async fn test_list_offers_pages_through_sorted_results() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    for salary in [900.0, 1500.0, 1200.0, 1100.0, 1300.0] {
+        let payload = json!({
+            "title": "Stage",
+            "link": "http://example.com",
+            "city": "Paris",
+            "domain": "IT",
+            "salary": salary,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true
+        });
+        let req = test::TestRequest::post()
+            .uri("/offer")
+            .set_json(&payload)
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
+    let mut salaries = Vec::new();
+    let mut uri = "/offer?limit=2&sort=-salary".to_string();
+    loop {
+        let req = test::TestRequest::get().uri(&uri).to_request();
+        let page: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+        assert!(page.items.len() <= 2);
+        salaries.extend(page.items.iter().map(|o| o.salary.amount));
+        match page.next_cursor {
+            Some(cursor) => uri = format!("/offer?limit=2&sort=-salary&cursor={}", cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(salaries, vec![150_000, 130_000, 120_000, 110_000, 90_000]);
+}
+
+#[actix_web::test]
+async fn test_salary_pages_include_offers_without_base_salary() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state.clone())).await;
+
+    let mut expected = Vec::new();
+    for salary in [1200.0, 900.0] {
+        let req = test::TestRequest::post()
+            .uri("/offer")
+            .set_json(json!({
+                "title": "Stage",
+                "link": "http://example.com",
+                "city": "Paris",
+                "domain": "IT",
+                "salary": salary,
+                "start_date": "2026-06-01",
+                "end_date": "2026-12-01",
+                "available": true
+            }))
+            .to_request();
+        let offer: Offer = test::call_and_read_body_json(&app, req).await;
+        expected.push(offer);
+    }
+    // Offers stored before salaries were converted have no base salary
+    for _ in 0..3 {
+        let legacy = Offer {
+            id: uuid::Uuid::new_v4(),
+            base_salary: None,
+            ..expected[0].clone()
+        };
+        state.offer_repo.save(&legacy).await.unwrap();
+        expected.push(legacy);
+    }
+
+    for sort in ["salary", "-salary"] {
+        let mut seen = Vec::new();
+        let mut uri = format!("/offer?limit=2&sort={}", sort);
+        loop {
+            let req = test::TestRequest::get().uri(&uri).to_request();
+            let page: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+            seen.extend(page.items);
+            match page.next_cursor {
+                Some(cursor) => uri = format!("/offer?limit=2&sort={}&cursor={}", sort, cursor),
+                None => break,
+            }
+        }
+
+        let mut ids = seen.iter().map(|o| o.id).collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), expected.len(), "{}", sort);
+        // Offers without a base salary come first in ascending order, last in descending order
+        let missing = seen
+            .iter()
+            .map(|o| o.base_salary.is_none())
+            .collect::<Vec<_>>();
+        let mut grouped = missing.clone();
+        grouped.sort_by_key(|none| (sort == "salary") != *none);
+        assert_eq!(missing, grouped, "{}", sort);
+    }
+}
+
+#[actix_web::test]
+async fn test_list_offers_with_invalid_sort_returns_400() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri("/offer?sort=link")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 400);
+}
+
+// ─── POST /offer/{id}/reserve + /release ───
+
+#[actix_web::test]
+async fn test_offer_becomes_unavailable_when_full() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage Places Limitées",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "IT",
+        "salary": 1000.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": true,
+        "seats": 2
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(created.seats, 2);
+    assert_eq!(created.reserved_seats, 0);
+
+    for expected_available in [true, false] {
+        let req = test::TestRequest::post()
+            .uri(&format!("/offer/{}/reserve", created.id))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let body: Offer = test::read_body_json(resp).await;
+        assert_eq!(body.available, expected_available);
+    }
+
+    // Full: a third reservation is refused
+    let req = test::TestRequest::post()
+        .uri(&format!("/offer/{}/reserve", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+
+    // Releasing a seat re-opens the offer
+    let req = test::TestRequest::post()
+        .uri(&format!("/offer/{}/release", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let body: Offer = test::read_body_json(resp).await;
+    assert!(body.available);
+    assert_eq!(body.reserved_seats, 1);
+}
+
+#[actix_web::test]
+async fn test_reserve_seat_on_unknown_offer_returns_404() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/offer/{}/reserve", uuid::Uuid::new_v4()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+}
+
+// ─── Admin view of hidden offers ───
+
+#[actix_web::test]
+async fn test_admin_sees_and_reactivates_hidden_offer() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage Masqué",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "IT",
+        "salary": 1000.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": false
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/admin/offer/{}", created.id))
+        .to_request();
+    let body: Offer = test::call_and_read_body_json(&app, req).await;
+    assert!(!body.available);
+
+    let req = test::TestRequest::get()
+        .uri("/admin/offer?visibility=hidden")
+        .to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 1);
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/offer/{}/reactivate", created.id))
+        .to_request();
+    let body: Offer = test::call_and_read_body_json(&app, req).await;
+    assert!(body.available);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+}
+
+#[actix_web::test]
+async fn test_update_can_make_hidden_offer_available_again() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage Masqué",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "IT",
+        "salary": 1000.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": false
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .set_json(json!({ "available": true }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let body: Offer = test::read_body_json(resp).await;
+    assert!(body.available);
+}
+
+#[actix_web::test]
+async fn test_full_offer_cannot_be_reactivated() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage Complet",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "IT",
+        "salary": 1000.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": true
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+
+    // Reserving the only seat hides the offer
+    let req = test::TestRequest::post()
+        .uri(&format!("/offer/{}/reserve", created.id))
+        .to_request();
+    test::call_service(&app, req).await;
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/offer/{}/reactivate", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+}
+
+// ─── Domain catalog ───
+
+#[actix_web::test]
+async fn test_offer_domain_is_resolved_against_catalog() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "title": "Stage Dev",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "informatique",
+        "salary": 1200.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": true
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(created.domain, "IT");
+
+    // Listing by alias finds the offer stored under the domain's name
+    let req = test::TestRequest::get()
+        .uri("/offer?domain=Computer%20Science")
+        .to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 1);
+
+    let mut unknown = payload.clone();
+    unknown["domain"] = json!("Astrologie");
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&unknown)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_offers_outside_the_catalog_stay_writable() {
+    // Before any domain is created, offers are stored under the domain they give
+    let empty = web::Data::new(AppState {
+        offer_repo: Box::new(InMemoryOfferRepository::new()),
+        revision_repo: Box::new(InMemoryOfferRevisionRepository::new()),
+        domain_repo: Box::new(InMemoryDomainRepository::with_domains(vec![])),
+        company_repo: Box::new(InMemoryCompanyRepository::new()),
+        clock: Arc::new(FixedClock::on(date("2026-05-01"))),
+        exchange_rates: Box::new(StaticExchangeRateProvider::new(test_rates())),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
+    });
+    let app = test::init_service(test_app(empty)).await;
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(json!({
+            "title": "Stage",
+            "link": "http://example.com",
+            "city": "Paris",
+            "domain": "Astrologie",
+            "salary": 1200.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true
+        }))
+        .to_request();
+    let legacy: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(legacy.domain, "Astrologie");
+
+    // Once the catalog is filled, the offer can still be edited without changing its domain
+    let state = test_app_state();
+    state.offer_repo.save(&legacy).await.unwrap();
+    let app = test::init_service(test_app(state)).await;
+    let update = |body: serde_json::Value| {
+        test::TestRequest::put()
+            .uri(&format!("/offer/{}", legacy.id))
+            .set_json(body)
+            .to_request()
+    };
+    let renamed: Offer =
+        test::call_and_read_body_json(&app, update(json!({ "title": "Stage Relu" }))).await;
+    assert_eq!(renamed.domain, "Astrologie");
+    let resent: Offer =
+        test::call_and_read_body_json(&app, update(json!({ "domain": "astrologie" }))).await;
+    assert_eq!(resent.domain, "Astrologie");
+
+    let resp = test::call_service(&app, update(json!({ "domain": "Alchimie" }))).await;
+    assert_eq!(resp.status(), 400);
+    let moved: Offer =
+        test::call_and_read_body_json(&app, update(json!({ "domain": "informatique" }))).await;
+    assert_eq!(moved.domain, "IT");
+}
+
+#[actix_web::test]
+async fn test_domain_crud_keeps_catalog_consistent() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/domain")
+        .set_json(json!({ "name": "Santé", "aliases": ["Health"] }))
+        .to_request();
+    let health: Domain = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::post()
+        .uri("/domain")
+        .set_json(json!({ "name": "Pharmacie", "parent_id": health.id }))
+        .to_request();
+    let pharmacy: Domain = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(pharmacy.parent_id, Some(health.id));
+
+    // Names and aliases are unique across the catalog, whatever the case or accents
+    let req = test::TestRequest::post()
+        .uri("/domain")
+        .set_json(json!({ "name": "SANTE" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+
+    // A domain cannot be moved under its own sub-domain
+    let req = test::TestRequest::put()
+        .uri(&format!("/domain/{}", health.id))
+        .set_json(json!({ "parent_id": pharmacy.id }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    // A parent cannot be deleted while it has sub-domains
+    let req = test::TestRequest::delete()
+        .uri(&format!("/domain/{}", health.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+
+    // Detaching the sub-domain makes it a root domain
+    let req = test::TestRequest::put()
+        .uri(&format!("/domain/{}", pharmacy.id))
+        .set_json(json!({ "parent_id": null }))
+        .to_request();
+    let detached: Domain = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(detached.parent_id, None);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/domain/{}", health.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 204);
+}
+
+#[actix_web::test]
+async fn test_offers_expire_after_their_deadline() {
+    let clock = Arc::new(FixedClock::on(date("2026-05-01")));
+    let state = test_app_state_with_clock(clock.clone());
+    let app = test::init_service(test_app(state.clone())).await;
+
+    let payload = json!({
+        "title": "Stage avec date limite",
+        "link": "http://example.com",
+        "city": "Paris",
+        "domain": "IT",
+        "salary": 1000.0,
+        "start_date": "2026-06-01",
+        "end_date": "2026-12-01",
+        "available": true,
+        "application_deadline": "2026-05-15"
+    });
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let with_deadline: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(with_deadline.application_deadline, Some(date("2026-05-15")));
+
+    let mut payload = payload;
+    payload
+        .as_object_mut()
+        .unwrap()
+        .remove("application_deadline");
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(&payload)
+        .to_request();
+    let without_deadline: Offer = test::call_and_read_body_json(&app, req).await;
+
+    // The deadline day itself is still open
+    clock.set(date("2026-05-15"));
+    assert!(expiry::expire_offers(&state).await.unwrap().is_empty());
+
+    clock.set(date("2026-05-16"));
+    let expired = expiry::expire_offers(&state).await.unwrap();
+    assert_eq!(
+        expired.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![with_deadline.id]
+    );
+    assert!(!expired[0].available);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}", with_deadline.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/offer/{}/reactivate", with_deadline.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+
+    // Without a deadline, the offer expires once the internship is over
+    clock.set(date("2026-12-02"));
+    let expired = expiry::expire_offers(&state).await.unwrap();
+    assert_eq!(
+        expired.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![without_deadline.id]
+    );
+    assert!(expiry::expire_offers(&state).await.unwrap().is_empty());
+}
+
+#[actix_web::test]
+async fn test_invalid_offer_payloads_are_rejected_with_every_field() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(json!({
+            "title": "",
+            "link": "not a url",
+            "city": "Paris",
+            "domain": "IT",
+            "salary": -500.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-01-01",
+            "available": true
+        }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body: ValidationFailure = test::read_body_json(resp).await;
+    let fields: Vec<&str> = body.fields.iter().map(|f| f.field.as_str()).collect();
+    assert_eq!(fields, vec!["title", "link", "salary", "end_date"]);
+
+    let req = test::TestRequest::get().uri("/offer").to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert!(body.items.is_empty());
+
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(json!({
+            "title": "Stage valide",
+            "link": "https://example.com/stage",
+            "city": "Paris",
+            "domain": "IT",
+            "salary": 1000.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true
+        }))
+        .to_request();
+    let created: Offer = test::call_and_read_body_json(&app, req).await;
+
+    // Dates of a partial update are checked against the stored offer
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created.id))
+        .set_json(json!({ "end_date": "2026-05-01" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let body: ValidationFailure = test::read_body_json(resp).await;
+    assert_eq!(body.fields[0].field, "end_date");
+}
+
+#[actix_web::test]
+async fn test_offers_reference_registered_companies() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/company")
+        .set_json(json!({
+            "name": "Rustacean GmbH",
+            "country": "Germany",
+            "city": "Berlin",
+            "website": "https://rustacean.example"
+        }))
+        .to_request();
+    let company: Company = test::call_and_read_body_json(&app, req).await;
+    assert!(!company.verified);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/company/{}", company.id))
+        .set_json(json!({ "verified": true }))
+        .to_request();
+    let company: Company = test::call_and_read_body_json(&app, req).await;
+    assert!(company.verified);
+    assert_eq!(company.city, "Berlin");
+
+    let offer = |company_id: Option<uuid::Uuid>| {
+        json!({
+            "title": "Stage Rust",
+            "link": "https://rustacean.example/stage",
+            "city": "Berlin",
+            "domain": "IT",
+            "salary": 1500.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true,
+            "company_id": company_id
+        })
+    };
+
+    // Offers may only point to a registered company
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(offer(Some(uuid::Uuid::new_v4())))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(offer(Some(company.id)))
+        .to_request();
+    let owned: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(owned.company_id, Some(company.id));
+
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(offer(None))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 201);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer?company_id={}", company.id))
+        .to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        body.items.iter().map(|o| o.id).collect::<Vec<_>>(),
+        vec![owned.id]
+    );
+
+    // A company cannot be deleted while offers, even archived ones, reference it
+    let req = test::TestRequest::delete()
+        .uri(&format!("/offer/{}", owned.id))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::delete()
+        .uri(&format!("/company/{}", company.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 409);
+
+    // Once restored and detached, the offer no longer holds the company
+    let req = test::TestRequest::post()
+        .uri(&format!("/admin/offer/{}/restore", owned.id))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", owned.id))
+        .set_json(json!({ "company_id": null }))
+        .to_request();
+    let detached: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(detached.company_id, None);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/company/{}", company.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 204);
+}
+
+#[actix_web::test]
+async fn test_offers_filtered_by_skill_language_and_remote_policy() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(json!({
+            "title": "Stage Data Engineer",
+            "link": "https://example.com/data",
+            "city": "Munich",
+            "domain": "IT",
+            "salary": 1300.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true,
+            "description": "## Missions\n- Pipelines **Rust**",
+            "skills": ["Rust", "Kafka"],
+            "languages": [{ "language": "Deutsch", "level": "B2" }],
+            "remote_policy": "hybrid"
+        }))
+        .to_request();
+    let detailed: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(detailed.skills, vec!["Rust", "Kafka"]);
+
+    // Offers created without the details are on-site with no requirement
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(json!({
+            "title": "Stage Support",
+            "link": "https://example.com/support",
+            "city": "Munich",
+            "domain": "IT",
+            "salary": 1100.0,
+            "start_date": "2026-06-01",
+            "end_date": "2026-12-01",
+            "available": true
+        }))
+        .to_request();
+    let plain: Offer = test::call_and_read_body_json(&app, req).await;
+    assert!(plain.skills.is_empty());
+
+    let ids = |page: Page<Offer>| page.items.into_iter().map(|o| o.id).collect::<Vec<_>>();
+    for (query, expected) in [
+        ("skill=rust", vec![detailed.id]),
+        ("language=deutsch&language_level=C1", vec![detailed.id]),
+        ("language=Deutsch&language_level=B1", vec![]),
+        ("remote_policy=on_site", vec![plain.id]),
+    ] {
+        let req = test::TestRequest::get()
+            .uri(&format!("/offer?{}", query))
+            .to_request();
+        let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(ids(body), expected, "{}", query);
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/offer?language_level=B2")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_salaries_in_other_currencies_are_compared_in_base_currency() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let mut created = Vec::new();
+    for (city, salary) in [
+        ("Paris", Money::new(150_000, "EUR", SalaryPeriod::Monthly)),
+        // 19 200 £ a year: 1 600 £, so 2 000 € a month
+        ("London", Money::new(1_920_000, "GBP", SalaryPeriod::Yearly)),
+        ("Berlin", Money::new(110_000, "EUR", SalaryPeriod::Monthly)),
+    ] {
+        let req = test::TestRequest::post()
+            .uri("/offer")
+            .set_json(json!({
+                "title": "Stage",
+                "link": "http://example.com",
+                "city": city,
+                "domain": "IT",
+                "salary": salary,
+                "start_date": "2026-06-01",
+                "end_date": "2026-12-01",
+                "available": true
+            }))
+            .to_request();
+        let offer: Offer = test::call_and_read_body_json(&app, req).await;
+        created.push(offer);
+    }
+    assert_eq!(
+        created[1].base_salary,
+        Some(Money::new(200_000, "EUR", SalaryPeriod::Monthly))
+    );
+
+    let cities = |page: Page<Offer>| page.items.into_iter().map(|o| o.city).collect::<Vec<_>>();
+    let req = test::TestRequest::get()
+        .uri("/offer?sort=-salary")
+        .to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(cities(body), vec!["London", "Paris", "Berlin"]);
+
+    // 1 000 £ a month is 1 250 €
+    let req = test::TestRequest::get()
+        .uri("/offer?min_salary=1000&currency=GBP&sort=salary")
+        .to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(cities(body), vec!["Paris", "London"]);
+
+    let req = test::TestRequest::get()
+        .uri("/offer?min_salary=1000&currency=JPY")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+
+    // A currency without exchange rate cannot be compared with the others
+    let req = test::TestRequest::put()
+        .uri(&format!("/offer/{}", created[0].id))
+        .set_json(json!({ "salary": { "amount": 20_000_000, "currency": "JPY" } }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_offers_are_imported_in_bulk_and_exported() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let header = "title,link,city,domain,salary_amount,salary_currency,salary_period,start_date,end_date,available,skills,languages,remote_policy";
+    let valid = [
+        "Stage Rust,https://example.com/rust,Berlin,Informatique,150000,EUR,monthly,2026-09-01,2027-02-28,true,Rust;Tokio,Deutsch:B1,hybrid",
+        "\"Stage Data, Londres\",https://example.com/data,London,IT,2040000,GBP,yearly,2026-09-01,2027-02-28,true,,,",
+    ];
+    let invalid =
+        "Stage,not a url,Paris,Astrologie,-1,EUR,monthly,2026-09-01,2027-02-28,true,,Deutsch,";
+    let csv = |lines: &[&str]| format!("{}\n{}\n", header, lines.join("\n"));
+
+    // One invalid line and nothing is written; every error is reported on its line
+    let req = test::TestRequest::post()
+        .uri("/offer/import")
+        .insert_header(("Content-Type", "text/csv"))
+        .set_payload(csv(&[valid[0], invalid, valid[1]]))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let report: routes::bulk::ImportReport = test::read_body_json(resp).await;
+    assert_eq!(report.imported, 0);
+    assert_eq!(
+        report.rows.iter().map(|r| r.line).collect::<Vec<_>>(),
+        vec![2, 3, 4]
+    );
+    assert!(report.rows[0].errors.is_empty());
+    let fields: Vec<&str> = report.rows[1]
+        .errors
+        .iter()
+        .map(|e| e.field.as_str())
+        .collect();
+    assert_eq!(fields, vec!["languages"]);
+
+    let req = test::TestRequest::post()
+        .uri("/offer/import?dry_run=true")
+        .insert_header(("Content-Type", "text/csv"))
+        .set_payload(csv(&valid))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let req = test::TestRequest::get().uri("/offer").to_request();
+    let body: Page<Offer> = test::call_and_read_body_json(&app, req).await;
+    assert!(body.items.is_empty());
+
+    let req = test::TestRequest::post()
+        .uri("/offer/import")
+        .insert_header(("Content-Type", "text/csv"))
+        .set_payload(csv(&valid))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 201);
+    let report: routes::bulk::ImportReport = test::read_body_json(resp).await;
+    assert_eq!(report.imported, 2);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}", report.rows[0].offer_id.unwrap()))
+        .to_request();
+    let rust: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(rust.domain, "IT");
+    assert_eq!(rust.skills, vec!["Rust", "Tokio"]);
+    assert_eq!(rust.remote_policy, RemotePolicy::Hybrid);
+
+    // An export can be imported again as is
+    let req = test::TestRequest::get()
+        .uri("/offer/export?format=csv")
+        .to_request();
+    let exported = test::call_and_read_body(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/offer/import?format=csv&dry_run=true")
+        .set_payload(exported)
+        .to_request();
+    let report: routes::bulk::ImportReport = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(report.rows.len(), 2);
+    assert!(report.rows.iter().all(|r| r.errors.is_empty()));
+
+    let req = test::TestRequest::get()
+        .uri("/offer/export?format=ndjson")
+        .to_request();
+    let exported = test::call_and_read_body(&app, req).await;
+    let offers: Vec<Offer> = std::str::from_utf8(&exported)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(offers.len(), 2);
+
+    let req = test::TestRequest::post()
+        .uri("/offer/import")
+        .insert_header(("Content-Type", "application/json"))
+        .set_payload("{}")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_csv_export_is_imported_back_unchanged() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/offer")
+        .set_json(json!({
+            "title": "Stage \"C; C++\", Lyon",
+            "link": "https://example.com/cpp",
+            "city": "Lyon",
+            "domain": "IT",
+            "salary": { "amount": 123_456, "currency": "GBP", "period": "yearly" },
+            "start_date": "2026-09-01",
+            "end_date": "2027-02-28",
+            "available": true,
+            "seats": 3,
+            "description": "Compilateurs;\nsur deux lignes",
+            "skills": ["C; C++", "C:\\Windows", "Rust"],
+            "languages": [
+                { "language": "Deutsch", "level": "B2" },
+                { "language": "Bahasa; Indonesia", "level": "A2" }
+            ],
+            "remote_policy": "remote"
+        }))
+        .to_request();
+    let original: Offer = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::get()
+        .uri("/offer/export?format=csv")
+        .to_request();
+    let exported = test::call_and_read_body(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/offer/import?format=csv")
+        .set_payload(exported)
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 201);
+    let report: routes::bulk::ImportReport = test::read_body_json(resp).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/offer/{}", report.rows[0].offer_id.unwrap()))
+        .to_request();
+    let imported: Offer = test::call_and_read_body_json(&app, req).await;
+    assert_ne!(imported.id, original.id);
+    assert_eq!(imported.title, original.title);
+    assert_eq!(imported.salary, original.salary);
+    assert_eq!(imported.seats, original.seats);
+    assert_eq!(imported.description, original.description);
+    assert_eq!(imported.skills, original.skills);
+    assert_eq!(imported.languages, original.languages);
+    assert_eq!(imported.remote_policy, original.remote_policy);
 }
@@ -1,5 +1,5 @@
 import { useState } from 'react';
-import { type AggregatedOffer } from '@/lib/api';
+import { type AggregatedOffer, type Money } from '@/lib/api';
 import CityScoreBars from '@/components/CityScoreBars';
 import { MapPin, Briefcase, ExternalLink, Activity, Newspaper, ChevronDown, ChevronUp, Tag, Calendar, DollarSign } from 'lucide-react';
 
@@ -18,8 +18,9 @@ function formatDate(dateStr: string) {
     return new Date(dateStr).toLocaleDateString('en-GB', { day: 'numeric', month: 'short', year: 'numeric' });
 }
 
-function formatSalary(salary: number) {
-    return new Intl.NumberFormat('en-US', { style: 'currency', currency: 'EUR', maximumFractionDigits: 0 }).format(salary);
+function formatSalary(salary: Money) {
+    const amount = new Intl.NumberFormat('en-US', { style: 'currency', currency: salary.currency, maximumFractionDigits: 0 }).format(salary.amount / 100);
+    return `${amount} / ${salary.period === 'yearly' ? 'year' : 'month'}`;
 }
 
 export default function OfferCard({ data, onApply, isApplying }: OfferCardProps) {
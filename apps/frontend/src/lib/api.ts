@@ -4,10 +4,30 @@ export interface Offer {
     link: string;
     city: string;
     domain: string;
-    salary: number;
+    salary: Money;
+    base_salary: Money | null;
     start_date: string;
     end_date: string;
     available: boolean;
+    seats: number;
+    reserved_seats: number;
+    description: string | null;
+    skills: string[];
+    languages: LanguageRequirement[];
+    remote_policy: 'on_site' | 'hybrid' | 'remote';
+    version: number;
+}
+
+/** Amount in minor units (cents, pence...) of an ISO 4217 currency. */
+export interface Money {
+    amount: number;
+    currency: string;
+    period: 'monthly' | 'yearly';
+}
+
+export interface LanguageRequirement {
+    language: string;
+    level: 'A1' | 'A2' | 'B1' | 'B2' | 'C1' | 'C2';
 }
 
 export interface CityScore {
@@ -47,16 +67,36 @@ export interface Student {
     firstname: string;
     name: string;
     domain: string;
+    email: string | null;
+    phone: string | null;
+    study_year: number | null;
+    languages: LanguageRequirement[];
+    cv: StoredFile | null;
+    version: number;
+}
+
+export interface StoredFile {
+    filename: string;
+    content_type: string;
+    size: number;
+    uploaded_at: string;
 }
 
+export type InternshipStatus = 'Submitted' | 'UnderReview' | 'Interview' | 'Accepted' | 'Declined' | 'Withdrawn';
+
 export interface Internship {
     id: string;
     student_id: string;
     offer_id: string;
-    status: 'Approved' | 'Rejected';
+    status: InternshipStatus;
     message: string;
 }
 
+export interface Page<T> {
+    items: T[];
+    next_cursor: string | null;
+}
+
 const API_BASE = 'http://localhost:8080';
 
 export async function fetchOffers(params?: { domain?: string; city?: string; limit?: number }): Promise<AggregatedOffer[]> {
@@ -68,7 +108,8 @@ export async function fetchOffers(params?: { domain?: string; city?: string; lim
     const qs = searchParams.toString();
     const res = await fetch(`${API_BASE}/offer${qs ? '?' + qs : ''}`);
     if (!res.ok) throw new Error('Failed to fetch offers');
-    return res.json();
+    const page: Page<AggregatedOffer> = await res.json();
+    return page.items;
 }
 
 export async function fetchStudent(id: string): Promise<Student> {
@@ -77,10 +118,25 @@ export async function fetchStudent(id: string): Promise<Student> {
     return res.json();
 }
 
+export async function searchStudents(params?: { name?: string; domain?: string; study_year?: number; limit?: number }): Promise<Student[]> {
+    const searchParams = new URLSearchParams();
+    if (params?.name) searchParams.append('name', params.name);
+    if (params?.domain) searchParams.append('domain', params.domain);
+    if (params?.study_year) searchParams.append('study_year', String(params.study_year));
+    if (params?.limit) searchParams.append('limit', String(params.limit));
+
+    const qs = searchParams.toString();
+    const res = await fetch(`${API_BASE}/student${qs ? '?' + qs : ''}`);
+    if (!res.ok) throw new Error('Failed to fetch students');
+    const page: Page<Student> = await res.json();
+    return page.items;
+}
+
 export async function fetchStudentsByDomain(domain: string): Promise<Student[]> {
     const res = await fetch(`${API_BASE}/student?domain=${encodeURIComponent(domain)}`);
     if (!res.ok) throw new Error('Failed to fetch students');
-    return res.json();
+    const page: Page<Student> = await res.json();
+    return page.items;
 }
 
 export async function createStudent(data: CreateStudentRequest): Promise<Student> {
@@ -132,12 +188,14 @@ export interface Notification {
     offerId: string;
     message: string;
     read: boolean;
+    stale: boolean;
 }
 
 export async function fetchNotifications(studentId: string): Promise<Notification[]> {
     const res = await fetch(`${API_BASE}/students/${studentId}/notifications`);
     if (!res.ok) throw new Error('Failed to fetch notifications');
-    return res.json();
+    const page: Page<Notification> = await res.json();
+    return page.items;
 }
 
 export async function markNotificationRead(notificationId: string): Promise<void> {
@@ -79,7 +79,7 @@ export default function OffersExplorer() {
             const result = await applyForInternship(student.id, offerId);
             setToast({
                 message: `Application ${result.status}! ${result.message}`,
-                type: result.status === 'Approved' ? 'success' : 'error'
+                type: result.status === 'Submitted' ? 'success' : 'error'
             });
         } catch (err: any) {
             setToast({ message: err.message, type: 'error' });
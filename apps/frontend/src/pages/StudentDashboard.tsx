@@ -121,7 +121,7 @@ export default function StudentDashboard() {
             const result = await applyForInternship(student.id, offerId);
             showToast(
                 `Application ${result.status}! ${result.message}`,
-                result.status === 'Approved' ? 'success' : 'error'
+                result.status === 'Submitted' ? 'success' : 'error'
             );
         } catch (err: any) {
             showToast(err.message, 'error');
@@ -6,6 +6,7 @@ use actix_web::{App, HttpResponse, HttpServer, web};
 use lapin::{Connection, ConnectionProperties, options::*};
 use serde::{Deserialize, Serialize};
 use tokio::sync::Mutex;
+use zukmove_core::domain::normalize::same_key;
 
 // ─── Data Model ───
 
@@ -30,6 +31,15 @@ struct StudentRegisteredEvent {
     #[allow(dead_code)]
     name: String,
     domain: String,
+    /// Absent from events published before students had contact details.
+    #[serde(default)]
+    email: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct StudentDeletedEvent {
+    #[serde(rename = "studentId")]
+    student_id: String,
 }
 
 #[derive(Deserialize)]
@@ -147,11 +157,12 @@ async fn run_consumers(
             match delivery {
                 Ok(delivery) => {
                     if let Ok(event) = serde_json::from_slice::<StudentRegisteredEvent>(&delivery.data) {
+                        // Students who gave no email keep an empty contact until they set one
                         let sub = Subscriber {
                             student_id: event.student_id.clone(),
                             domain: event.domain,
                             channel: "email".to_string(),
-                            contact: String::new(),
+                            contact: event.email.unwrap_or_default(),
                             enabled: true,
                         };
                         store_clone.lock().await.insert(event.student_id.clone(), sub);
@@ -164,6 +175,63 @@ async fn run_consumers(
         }
     });
 
+    // --- student.deleted consumer ---
+    let deleted_channel = conn.create_channel().await?;
+    deleted_channel
+        .queue_declare(
+            "laposte.student.deleted",
+            QueueDeclareOptions {
+                durable: true,
+                ..Default::default()
+            },
+            Default::default(),
+        )
+        .await?;
+    deleted_channel
+        .queue_bind(
+            "laposte.student.deleted",
+            "zukmove.events",
+            "student.deleted",
+            QueueBindOptions::default(),
+            Default::default(),
+        )
+        .await?;
+
+    let deleted_consumer = deleted_channel
+        .basic_consume(
+            "laposte.student.deleted",
+            "laposte-student-deleted",
+            BasicConsumeOptions::default(),
+            Default::default(),
+        )
+        .await?;
+
+    let store_clone3 = store.clone();
+    tokio::spawn(async move {
+        use futures_lite::StreamExt;
+        let mut consumer = deleted_consumer;
+        while let Some(delivery) = consumer.next().await {
+            match delivery {
+                Ok(delivery) => {
+                    if let Ok(event) = serde_json::from_slice::<StudentDeletedEvent>(&delivery.data)
+                    {
+                        // Erasure: the subscriber holds the student's contact, drop it entirely
+                        if store_clone3
+                            .lock()
+                            .await
+                            .remove(&event.student_id)
+                            .is_some()
+                        {
+                            log::info!("Erased subscriber: {}", event.student_id);
+                        }
+                    }
+                    let _ = delivery.ack(BasicAckOptions::default()).await;
+                }
+                Err(e) => log::error!("Consumer error: {}", e),
+            }
+        }
+    });
+
     // --- offer.created consumer ---
     let offer_channel = conn.create_channel().await?;
     offer_channel
@@ -187,7 +255,7 @@ async fn run_consumers(
                     if let Ok(event) = serde_json::from_slice::<OfferCreatedEvent>(&delivery.data) {
                         let store = store_clone2.lock().await;
                         for (id, sub) in store.iter() {
-                            if sub.enabled && sub.domain.to_lowercase() == event.domain.to_lowercase() {
+                            if sub.enabled && same_key(&sub.domain, &event.domain) {
                                 log::info!(
                                     "[MOCK ALERT] Notifying {} via {} ({}): New offer '{}' in {}",
                                     id,
@@ -206,7 +274,9 @@ async fn run_consumers(
         }
     });
 
-    log::info!("La Poste: RabbitMQ consumers started (student.registered, offer.created)");
+    log::info!(
+        "La Poste: RabbitMQ consumers started (student.registered, student.deleted, offer.created)"
+    );
     Ok(())
 }
 
@@ -2,14 +2,17 @@ use std::sync::Mutex;
 
 use async_trait::async_trait;
 use zukmove_core::domain::entities::news::News;
+use zukmove_core::domain::normalize::same_key;
 use zukmove_core::domain::ports::{DomainError, NewsRepository};
 
 /// In-memory NewsRepository backed by a Vec, with hardcoded fake data.
+#[allow(dead_code)]
 pub struct ArrayNewsRepository {
     store: Mutex<Vec<News>>,
 }
 
 impl ArrayNewsRepository {
+    #[allow(dead_code)]
     pub fn new() -> Self {
         let fake_data = vec![
             News {
@@ -86,7 +89,7 @@ impl NewsRepository for ArrayNewsRepository {
         let store = self.store.lock().unwrap();
         Ok(store
             .iter()
-            .filter(|n| n.city.to_lowercase() == city.to_lowercase())
+            .filter(|n| same_key(&n.city, city))
             .take(limit)
             .cloned()
             .collect())
@@ -3,14 +3,17 @@ use std::sync::Mutex;
 
 use async_trait::async_trait;
 use zukmove_core::domain::entities::city_score::CityScore;
+use zukmove_core::domain::normalize::canonical_key;
 use zukmove_core::domain::ports::{CityScoreRepository, DomainError};
 
 /// In-memory CityScoreRepository for Phase 1 validation.
+#[allow(dead_code)]
 pub struct InMemoryCityScoreRepository {
     store: Mutex<HashMap<String, CityScore>>,
 }
 
 impl InMemoryCityScoreRepository {
+    #[allow(dead_code)]
     pub fn new() -> Self {
         Self {
             store: Mutex::new(HashMap::new()),
@@ -22,7 +25,7 @@ impl InMemoryCityScoreRepository {
 impl CityScoreRepository for InMemoryCityScoreRepository {
     async fn get_or_create(&self, city: &str, country: &str) -> Result<CityScore, DomainError> {
         let mut store = self.store.lock().unwrap();
-        let key = city.to_lowercase();
+        let key = canonical_key(city);
         if let Some(score) = store.get(&key) {
             Ok(score.clone())
         } else {
@@ -34,7 +37,7 @@ impl CityScoreRepository for InMemoryCityScoreRepository {
 
     async fn save(&self, score: &CityScore) -> Result<CityScore, DomainError> {
         let mut store = self.store.lock().unwrap();
-        let key = score.city.to_lowercase();
+        let key = canonical_key(&score.city);
         store.insert(key, score.clone());
         Ok(score.clone())
     }
@@ -42,7 +45,7 @@ impl CityScoreRepository for InMemoryCityScoreRepository {
     async fn get_top_cities(&self, limit: usize) -> Result<Vec<CityScore>, DomainError> {
         let store = self.store.lock().unwrap();
         let mut scores: Vec<CityScore> = store.values().cloned().collect();
-        scores.sort_by(|a, b| a.total_score().cmp(&b.total_score())); // ascending
+        scores.sort_by_key(|s| s.total_score()); // ascending
         Ok(scores.into_iter().take(limit).collect())
     }
 }
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use redis::AsyncCommands;
 use zukmove_core::domain::entities::city_score::CityScore;
+use zukmove_core::domain::normalize::canonical_key;
 use zukmove_core::domain::ports::{CityScoreRepository, DomainError};
 
 /// Redis-backed CityScoreRepository.
@@ -31,7 +32,7 @@ impl RedisCityScoreRepository {
 impl CityScoreRepository for RedisCityScoreRepository {
     async fn get_or_create(&self, city: &str, country: &str) -> Result<CityScore, DomainError> {
         let mut conn = self.get_connection().await?;
-        let data_key = format!("cityscore:data:{}", city.to_lowercase());
+        let data_key = format!("cityscore:data:{}", canonical_key(city));
 
         let json: Option<String> = conn
             .get(&data_key)
@@ -52,7 +53,7 @@ impl CityScoreRepository for RedisCityScoreRepository {
 
     async fn save(&self, score: &CityScore) -> Result<CityScore, DomainError> {
         let mut conn = self.get_connection().await?;
-        let data_key = format!("cityscore:data:{}", score.city.to_lowercase());
+        let data_key = format!("cityscore:data:{}", canonical_key(&score.city));
 
         let json = serde_json::to_string(score)
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
@@ -67,7 +68,7 @@ impl CityScoreRepository for RedisCityScoreRepository {
         let _: () = conn
             .zadd(
                 "cityscore:ranking",
-                &score.city.to_lowercase(),
+                canonical_key(&score.city),
                 score.total_score(),
             )
             .await
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 use redis::AsyncCommands;
 use zukmove_core::domain::entities::news::News;
+use zukmove_core::domain::normalize::canonical_key;
 use zukmove_core::domain::ports::{DomainError, NewsRepository};
 
 /// Redis-backed NewsRepository using Sorted Sets.
@@ -44,7 +45,7 @@ impl NewsRepository for RedisNewsRepository {
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
         let score = Self::date_to_score(&news.date);
-        let city_key = format!("news:city:{}", news.city.to_lowercase());
+        let city_key = format!("news:city:{}", canonical_key(&news.city));
         let data_key = format!("news:data:{}", news.id);
 
         // Store the JSON data
@@ -98,7 +99,7 @@ impl NewsRepository for RedisNewsRepository {
     async fn get_latest_in_city(&self, city: &str, limit: usize) -> Result<Vec<News>, DomainError> {
         let mut conn = self.get_connection().await?;
 
-        let city_key = format!("news:city:{}", city.to_lowercase());
+        let city_key = format!("news:city:{}", canonical_key(city));
 
         let ids: Vec<String> = conn
             .zrevrange(&city_key, 0, (limit as isize) - 1)
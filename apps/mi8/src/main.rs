@@ -5,7 +5,6 @@ use std::collections::HashMap;
 use std::sync::Arc;
 
 use lapin::{Connection, ConnectionProperties, options::*};
-use tokio::sync::Mutex;
 use tonic::transport::Server;
 
 mod proto {
@@ -17,6 +16,8 @@ use adapters::redis_news_repo::RedisNewsRepository;
 use proto::mi8_service_server::Mi8ServiceServer;
 use service::Mi8ServiceImpl;
 use zukmove_core::domain::entities::news::News;
+use zukmove_core::domain::entities::offer::OfferUpdatedEvent;
+use zukmove_core::domain::normalize::canonical_key;
 use zukmove_core::domain::ports::{CityScoreRepository, NewsRepository};
 
 #[derive(serde::Deserialize)]
@@ -46,6 +47,28 @@ struct CityStatsData {
     last_offer_date: String,
 }
 
+impl CityStatsData {
+    /// Adds `delta` offers of `domain`; counters never go below zero and empty domains are dropped.
+    fn record(&mut self, domain: &str, delta: i32) {
+        self.total_offers = (self.total_offers + delta).max(0);
+        let count = self.offers_by_domain.entry(domain.to_string()).or_insert(0);
+        *count = (*count + delta).max(0);
+        if *count == 0 {
+            self.offers_by_domain.remove(domain);
+        }
+    }
+}
+
+/// Offer lifecycle events reflected in the city stats
+const OFFER_ROUTING_KEYS: [&str; 6] = [
+    "offer.created",
+    "offer.updated",
+    "offer.deleted",
+    "offer.restored",
+    "offer.expired",
+    "offer.reactivated",
+];
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     dotenv::dotenv().ok();
@@ -163,14 +186,24 @@ async fn run_rabbitmq_consumers(
         }
     });
 
-    // --- offer.created consumer ---
+    // --- offer events consumer ---
+    // A single queue keeps created/updated/deleted events of an offer in order; it keeps its
+    // original name so that messages already queued are not lost.
     let offer_channel = conn.create_channel().await?;
     offer_channel
         .queue_declare("mi8.offer.created", QueueDeclareOptions { durable: true, ..Default::default() }, Default::default())
         .await?;
-    offer_channel
-        .queue_bind("mi8.offer.created", "zukmove.events", "offer.created", QueueBindOptions::default(), Default::default())
-        .await?;
+    for routing_key in OFFER_ROUTING_KEYS {
+        offer_channel
+            .queue_bind(
+                "mi8.offer.created",
+                "zukmove.events",
+                routing_key,
+                QueueBindOptions::default(),
+                Default::default(),
+            )
+            .await?;
+    }
 
     let offer_consumer = offer_channel
         .basic_consume("mi8.offer.created", "mi8-offer", BasicConsumeOptions::default(), Default::default())
@@ -184,8 +217,11 @@ async fn run_rabbitmq_consumers(
         while let Some(delivery) = consumer.next().await {
             match delivery {
                 Ok(delivery) => {
-                    if let Err(e) = process_offer_event(&delivery.data, &redis_client_c).await {
-                        log::error!("Failed to process offer event: {}", e);
+                    let routing_key = delivery.routing_key.as_str();
+                    if let Err(e) =
+                        process_offer_event(routing_key, &delivery.data, &redis_client_c).await
+                    {
+                        log::error!("Failed to process {} event: {}", routing_key, e);
                     }
                     let _ = delivery.ack(BasicAckOptions::default()).await;
                 }
@@ -194,7 +230,7 @@ async fn run_rabbitmq_consumers(
         }
     });
 
-    log::info!("MI8 RabbitMQ consumers started (news.created, offer.created)");
+    log::info!("MI8 RabbitMQ consumers started (news.created, offer.*)");
     Ok(())
 }
 
@@ -234,39 +270,74 @@ async fn process_news_event(
 }
 
 async fn process_offer_event(
+    routing_key: &str,
     data: &[u8],
     redis_client: &redis::Client,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-    let event: OfferEvent = serde_json::from_slice(data)?;
-
     let mut conn = redis_client.get_multiplexed_async_connection().await?;
-    let key = format!("citystats:data:{}", event.city.to_lowercase());
 
-    let existing: Option<String> = redis::AsyncCommands::get(&mut conn, &key).await?;
+    match routing_key {
+        "offer.updated" => {
+            let event: OfferUpdatedEvent = serde_json::from_slice(data)?;
+            if !event.changed("city") && !event.changed("domain") {
+                return Ok(());
+            }
+            // Move the offer from its previous (city, domain) bucket to the new one
+            let offer = &event.offer;
+            let start_date = offer.start_date.to_string();
+            let previous_city = event.previous_str("city", &offer.city);
+            let previous_domain = event.previous_str("domain", &offer.domain);
+            update_city_stats(&mut conn, previous_city, previous_domain, -1, "").await?;
+            update_city_stats(&mut conn, &offer.city, &offer.domain, 1, &start_date).await?;
+        }
+        "offer.deleted" | "offer.expired" => {
+            let event: OfferEvent = serde_json::from_slice(data)?;
+            update_city_stats(&mut conn, &event.city, &event.domain, -1, "").await?;
+        }
+        // offer.created, offer.restored and offer.reactivated
+        _ => {
+            let event: OfferEvent = serde_json::from_slice(data)?;
+            update_city_stats(&mut conn, &event.city, &event.domain, 1, &event.start_date).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn update_city_stats(
+    conn: &mut redis::aio::MultiplexedConnection,
+    city: &str,
+    domain: &str,
+    delta: i32,
+    start_date: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let key = format!("citystats:data:{}", canonical_key(city));
+
+    let existing: Option<String> = redis::AsyncCommands::get(conn, &key).await?;
 
     let mut stats: CityStatsData = if let Some(json) = existing {
         serde_json::from_str(&json)?
     } else {
         CityStatsData {
-            city: event.city.clone(),
+            city: city.to_string(),
             ..Default::default()
         }
     };
 
-    stats.total_offers += 1;
-    *stats.offers_by_domain.entry(event.domain.clone()).or_insert(0) += 1;
-    if !event.start_date.is_empty() {
-        stats.last_offer_date = event.start_date;
-    } else {
-        stats.last_offer_date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+    stats.record(domain, delta);
+    if delta > 0 {
+        if !start_date.is_empty() {
+            stats.last_offer_date = start_date.to_string();
+        } else {
+            stats.last_offer_date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        }
     }
 
     let json = serde_json::to_string(&stats)?;
-    let _: () = redis::AsyncCommands::set(&mut conn, &key, &json).await?;
+    let _: () = redis::AsyncCommands::set(conn, &key, &json).await?;
 
     log::info!(
         "Updated city stats for {}: {} total offers",
-        event.city,
+        city,
         stats.total_offers
     );
     Ok(())
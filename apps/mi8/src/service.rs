@@ -8,6 +8,7 @@ use crate::proto::{
     GetTopCitiesRequest, News as ProtoNews, NewsList,
 };
 use zukmove_core::domain::entities::news::News;
+use zukmove_core::domain::normalize::canonical_key;
 use zukmove_core::domain::ports::{CityScoreRepository, NewsRepository};
 
 #[derive(serde::Deserialize, Default)]
@@ -210,7 +211,7 @@ impl Mi8Service for Mi8ServiceImpl {
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
 
-        let key = format!("citystats:data:{}", city.to_lowercase());
+        let key = format!("citystats:data:{}", canonical_key(&city));
         let json: Option<String> = redis::AsyncCommands::get(&mut conn, &key)
             .await
             .map_err(|e| Status::internal(e.to_string()))?;
@@ -0,0 +1,13 @@
+-- Map legacy instant decisions onto the new lifecycle
+UPDATE internships SET status = 'Accepted' WHERE status = 'Approved';
+UPDATE internships SET status = 'Declined' WHERE status = 'Rejected';
+
+-- Create internship status history table
+CREATE TABLE IF NOT EXISTS internship_status_history (
+    id BIGSERIAL PRIMARY KEY,
+    internship_id UUID NOT NULL REFERENCES internships(id),
+    from_status VARCHAR(20),
+    to_status VARCHAR(20) NOT NULL,
+    message TEXT NOT NULL,
+    changed_at TIMESTAMPTZ NOT NULL DEFAULT now()
+);
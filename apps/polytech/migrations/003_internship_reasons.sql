@@ -0,0 +1,2 @@
+-- Structured eligibility violations collected at registration
+ALTER TABLE internships ADD COLUMN IF NOT EXISTS reasons JSONB NOT NULL DEFAULT '[]';
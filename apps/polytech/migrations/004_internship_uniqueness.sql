@@ -0,0 +1,2 @@
+-- No-op: the uniqueness index of applications is created by 010_internship_reapply.sql.
+-- Duplicate applications that prevent it are cleaned by hand with scripts/dedupe_internships.sql.
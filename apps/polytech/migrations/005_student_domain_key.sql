@@ -0,0 +1,5 @@
+-- Canonical domain key (accents stripped, case folded, whitespace trimmed).
+-- Existing rows are backfilled by the service at startup, since plain SQL cannot strip accents.
+ALTER TABLE students ADD COLUMN IF NOT EXISTS domain_key VARCHAR(100);
+
+CREATE INDEX IF NOT EXISTS students_domain_key_idx ON students (domain_key);
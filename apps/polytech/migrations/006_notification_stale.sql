@@ -0,0 +1,2 @@
+-- Set when the offer a notification points to is updated or deleted after it was sent.
+ALTER TABLE notifications ADD COLUMN IF NOT EXISTS stale BOOLEAN NOT NULL DEFAULT false;
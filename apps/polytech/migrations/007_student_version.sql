@@ -0,0 +1,3 @@
+-- Version of each student, incremented by every update and used as its ETag.
+-- An update only applies if the version is still the one the client read.
+ALTER TABLE students ADD COLUMN IF NOT EXISTS version BIGINT NOT NULL DEFAULT 1;
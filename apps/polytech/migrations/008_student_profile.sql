@@ -0,0 +1,8 @@
+-- Contact details and profile of each student.
+-- `cv` describes the uploaded file (name, type, size); its content is kept by the file storage.
+ALTER TABLE students
+    ADD COLUMN IF NOT EXISTS email VARCHAR(254),
+    ADD COLUMN IF NOT EXISTS phone VARCHAR(32),
+    ADD COLUMN IF NOT EXISTS study_year SMALLINT,
+    ADD COLUMN IF NOT EXISTS languages JSONB NOT NULL DEFAULT '[]',
+    ADD COLUMN IF NOT EXISTS cv JSONB;
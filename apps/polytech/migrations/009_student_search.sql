@@ -0,0 +1,8 @@
+-- Canonical keys of first and last names, for the accent-insensitive prefix search of GET /student.
+-- Existing rows are backfilled by the service at startup, like domain_key.
+ALTER TABLE students
+    ADD COLUMN IF NOT EXISTS firstname_key VARCHAR(255),
+    ADD COLUMN IF NOT EXISTS name_key VARCHAR(255);
+
+CREATE INDEX IF NOT EXISTS students_name_key_idx ON students (name_key text_pattern_ops);
+CREATE INDEX IF NOT EXISTS students_firstname_key_idx ON students (firstname_key text_pattern_ops);
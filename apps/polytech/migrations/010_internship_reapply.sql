@@ -0,0 +1,7 @@
+-- One live application per student and offer: once declined or withdrawn,
+-- the student may apply to the same offer again.
+-- Creating the index fails while duplicate live applications remain: clean them with scripts/dedupe_internships.sql.
+DROP INDEX IF EXISTS internships_student_offer_key;
+
+CREATE UNIQUE INDEX IF NOT EXISTS internships_student_offer_active_key ON internships (student_id, offer_id)
+    WHERE status NOT IN ('Withdrawn', 'Declined');
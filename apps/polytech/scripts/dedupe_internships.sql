@@ -0,0 +1,43 @@
+-- One-off cleanup of duplicate applications, run by hand before the uniqueness index
+-- (see migrations/010_internship_reapply.sql) can be created; the service never runs it:
+--   psql "$DATABASE_URL" -v ON_ERROR_STOP=1 -f apps/polytech/scripts/dedupe_internships.sql
+--
+-- For each student and offer, the earliest live application (first history entry, then id) is kept.
+-- Later duplicates still Submitted are withdrawn, with a history entry, rather than deleted.
+-- The script refuses to run, and changes nothing, if a later duplicate has moved past Submitted:
+-- those need a human decision.
+-- Each withdrawn duplicate still holds a seat in Erasmumu: release it with POST /offer/{id}/release.
+BEGIN;
+
+CREATE TEMP TABLE internship_duplicates ON COMMIT DROP AS
+SELECT id, offer_id, status
+FROM (
+    SELECT i.id, i.offer_id, i.status,
+           row_number() OVER (
+               PARTITION BY i.student_id, i.offer_id
+               ORDER BY (SELECT min(h.changed_at) FROM internship_status_history h WHERE h.internship_id = i.id) NULLS LAST, i.id
+           ) AS rank
+    FROM internships i
+    WHERE i.status NOT IN ('Withdrawn', 'Declined')
+) ranked
+WHERE rank > 1;
+
+DO $$
+BEGIN
+    IF EXISTS (SELECT 1 FROM internship_duplicates WHERE status <> 'Submitted') THEN
+        RAISE EXCEPTION 'Duplicate applications have moved past Submitted: resolve them by hand (%)',
+            (SELECT string_agg(id::text, ', ') FROM internship_duplicates WHERE status <> 'Submitted');
+    END IF;
+END $$;
+
+INSERT INTO internship_status_history (internship_id, from_status, to_status, message)
+SELECT id, status, 'Withdrawn', 'Candidature en double retirée'
+FROM internship_duplicates;
+
+UPDATE internships SET status = 'Withdrawn'
+WHERE id IN (SELECT id FROM internship_duplicates);
+
+-- Offers whose seats must be released, one row per withdrawn duplicate
+SELECT offer_id FROM internship_duplicates;
+
+COMMIT;
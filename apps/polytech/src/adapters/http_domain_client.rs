@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use zukmove_core::domain::entities::domain::{Domain, DomainTaxonomy};
+use zukmove_core::domain::ports::{DomainClient, DomainError};
+
+pub struct HttpDomainClient {
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl HttpDomainClient {
+    pub fn new(base_url: String) -> Self {
+        Self {
+            base_url,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainClient for HttpDomainClient {
+    async fn get_taxonomy(&self) -> Result<DomainTaxonomy, DomainError> {
+        let url = format!("{}/domain", self.base_url);
+        let response =
+            self.client.get(&url).send().await.map_err(|e| {
+                DomainError::InfrastructureError(format!("HTTP request failed: {}", e))
+            })?;
+
+        if !response.status().is_success() {
+            return Err(DomainError::InfrastructureError(format!(
+                "Erasmumu returned status {}",
+                response.status()
+            )));
+        }
+
+        let domains = response.json::<Vec<Domain>>().await.map_err(|e| {
+            DomainError::InfrastructureError(format!("Failed to parse domains: {}", e))
+        })?;
+        Ok(DomainTaxonomy::new(domains))
+    }
+}
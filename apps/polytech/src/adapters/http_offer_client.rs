@@ -1,7 +1,9 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::offer::Offer;
+use zukmove_core::domain::entities::company::Company;
+use zukmove_core::domain::entities::offer::{Offer, OfferFilter};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 use zukmove_core::domain::ports::{DomainError, OfferClient};
 
 pub struct HttpOfferClient {
@@ -16,6 +18,41 @@ impl HttpOfferClient {
             client: reqwest::Client::new(),
         }
     }
+
+    async fn post_seat_action(&self, id: Uuid, action: &str) -> Result<Offer, DomainError> {
+        let url = format!("{}/offer/{}/{}", self.base_url, id, action);
+        let response =
+            self.client.post(&url).send().await.map_err(|e| {
+                DomainError::InfrastructureError(format!("HTTP request failed: {}", e))
+            })?;
+
+        match response.status() {
+            reqwest::StatusCode::NOT_FOUND => {
+                return Err(DomainError::NotFound(format!(
+                    "Offer with id {} not found on Erasmumu",
+                    id
+                )));
+            }
+            reqwest::StatusCode::CONFLICT => {
+                return Err(DomainError::Conflict(format!(
+                    "Erasmumu refused to {} a seat on offer {}",
+                    action, id
+                )));
+            }
+            status if !status.is_success() => {
+                return Err(DomainError::InfrastructureError(format!(
+                    "Erasmumu returned status {}",
+                    status
+                )));
+            }
+            _ => {}
+        }
+
+        response
+            .json::<Offer>()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(format!("Failed to parse offer: {}", e)))
+    }
 }
 
 #[async_trait]
@@ -48,18 +85,13 @@ impl OfferClient for HttpOfferClient {
     }
     async fn search_offers(
         &self,
-        domain: Option<String>,
-        city: Option<String>,
-    ) -> Result<Vec<Offer>, DomainError> {
+        filter: &OfferFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Offer>, DomainError> {
         let url = format!("{}/offer", self.base_url);
 
-        let mut query = Vec::new();
-        if let Some(d) = domain {
-            query.push(("domain", d));
-        }
-        if let Some(c) = city {
-            query.push(("city", c));
-        }
+        let mut query = filter.to_query();
+        query.extend(page.to_query());
 
         let response = self
             .client
@@ -77,8 +109,42 @@ impl OfferClient for HttpOfferClient {
         }
 
         response
-            .json::<Vec<Offer>>()
+            .json::<Page<Offer>>()
             .await
             .map_err(|e| DomainError::InfrastructureError(format!("Failed to parse offers: {}", e)))
     }
+
+    async fn reserve_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
+        self.post_seat_action(id, "reserve").await
+    }
+
+    async fn release_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
+        self.post_seat_action(id, "release").await
+    }
+
+    async fn get_company(&self, id: Uuid) -> Result<Company, DomainError> {
+        let url = format!("{}/company/{}", self.base_url, id);
+        let response =
+            self.client.get(&url).send().await.map_err(|e| {
+                DomainError::InfrastructureError(format!("HTTP request failed: {}", e))
+            })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Err(DomainError::NotFound(format!(
+                "Company with id {} not found on Erasmumu",
+                id
+            )));
+        }
+
+        if !response.status().is_success() {
+            return Err(DomainError::InfrastructureError(format!(
+                "Erasmumu returned status {}",
+                response.status()
+            )));
+        }
+
+        response.json::<Company>().await.map_err(|e| {
+            DomainError::InfrastructureError(format!("Failed to parse company: {}", e))
+        })
+    }
 }
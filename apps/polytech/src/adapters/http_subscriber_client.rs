@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::subscriber::Subscriber;
+use zukmove_core::domain::ports::{DomainError, SubscriberClient};
+
+pub struct HttpSubscriberClient {
+    base_url: String,
+    client: reqwest::Client,
+}
+
+impl HttpSubscriberClient {
+    pub fn new(base_url: String) -> Self {
+        Self {
+            base_url,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[async_trait]
+impl SubscriberClient for HttpSubscriberClient {
+    async fn get_subscriber(&self, student_id: Uuid) -> Result<Option<Subscriber>, DomainError> {
+        let url = format!("{}/subscribers/{}", self.base_url, student_id);
+        let response =
+            self.client.get(&url).send().await.map_err(|e| {
+                DomainError::InfrastructureError(format!("HTTP request failed: {}", e))
+            })?;
+
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        if !response.status().is_success() {
+            return Err(DomainError::InfrastructureError(format!(
+                "La Poste returned status {}",
+                response.status()
+            )));
+        }
+
+        let subscriber = response.json::<Subscriber>().await.map_err(|e| {
+            DomainError::InfrastructureError(format!("Failed to parse subscriber: {}", e))
+        })?;
+        Ok(Some(subscriber))
+    }
+}
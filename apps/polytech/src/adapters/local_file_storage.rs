@@ -0,0 +1,71 @@
+use std::io::ErrorKind;
+use std::path::{Component, Path, PathBuf};
+
+use async_trait::async_trait;
+
+use zukmove_core::domain::ports::{DomainError, FileStorage};
+
+/// Files stored on the local filesystem, one file per key under a root directory.
+pub struct LocalFileStorage {
+    root: PathBuf,
+}
+
+impl LocalFileStorage {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// Keys are relative paths of plain segments: `..` or an absolute key would escape the root.
+    fn path(&self, key: &str) -> Result<PathBuf, DomainError> {
+        let relative = Path::new(key);
+        if key.is_empty()
+            || !relative
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)))
+        {
+            return Err(DomainError::ValidationError(format!(
+                "Invalid file key {}",
+                key
+            )));
+        }
+        Ok(self.root.join(relative))
+    }
+}
+
+#[async_trait]
+impl FileStorage for LocalFileStorage {
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), DomainError> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        }
+        // Written aside then renamed, so a reader never sees a half-written file
+        let partial = path.with_extension("partial");
+        tokio::fs::write(&partial, content)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+        tokio::fs::rename(&partial, &path)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, DomainError> {
+        match tokio::fs::read(self.path(key)?).await {
+            Ok(content) => Ok(content),
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                Err(DomainError::NotFound(format!("File {} not found", key)))
+            }
+            Err(e) => Err(DomainError::InfrastructureError(e.to_string())),
+        }
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DomainError> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(DomainError::InfrastructureError(e.to_string())),
+        }
+    }
+}
@@ -1,5 +1,8 @@
 pub mod grpc_news_client;
+pub mod http_domain_client;
 pub mod http_offer_client;
+pub mod http_subscriber_client;
+pub mod local_file_storage;
 pub mod postgres_internship;
 pub mod postgres_notification;
 pub mod postgres_student;
@@ -1,17 +1,24 @@
 use async_trait::async_trait;
-use sqlx::{FromRow, PgPool};
+use chrono::{DateTime, Utc};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::internship::{Internship, InternshipStatus};
+use zukmove_core::domain::eligibility::EligibilityViolation;
+use zukmove_core::domain::entities::internship::{
+    Internship, InternshipFilter, InternshipStatus, InternshipStatusChange,
+};
+use zukmove_core::domain::pagination::{Page, PageRequest, SortDirection};
 use zukmove_core::domain::ports::{DomainError, InternshipRepository};
 
 #[derive(Debug, FromRow)]
-struct InternshipRow {
+pub(crate) struct InternshipRow {
     id: Uuid,
     student_id: Uuid,
     offer_id: Uuid,
     status: String,
     message: String,
+    reasons: Json<Vec<EligibilityViolation>>,
 }
 
 impl TryFrom<InternshipRow> for Internship {
@@ -28,6 +35,39 @@ impl TryFrom<InternshipRow> for Internship {
             offer_id: row.offer_id,
             status,
             message: row.message,
+            reasons: row.reasons.0,
+        })
+    }
+}
+
+#[derive(Debug, FromRow)]
+struct StatusChangeRow {
+    internship_id: Uuid,
+    from_status: Option<String>,
+    to_status: String,
+    message: String,
+    changed_at: DateTime<Utc>,
+}
+
+impl TryFrom<StatusChangeRow> for InternshipStatusChange {
+    type Error = DomainError;
+
+    fn try_from(row: StatusChangeRow) -> Result<Self, Self::Error> {
+        let from = row
+            .from_status
+            .map(|s| s.parse::<InternshipStatus>())
+            .transpose()
+            .map_err(DomainError::InfrastructureError)?;
+        let to = row
+            .to_status
+            .parse()
+            .map_err(|e: String| DomainError::InfrastructureError(e))?;
+        Ok(InternshipStatusChange {
+            internship_id: row.internship_id,
+            from,
+            to,
+            message: row.message,
+            changed_at: row.changed_at,
         })
     }
 }
@@ -46,24 +86,53 @@ impl PostgresInternshipRepository {
 impl InternshipRepository for PostgresInternshipRepository {
     async fn save(&self, internship: &Internship) -> Result<Internship, DomainError> {
         let status_str = internship.status.to_string();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
         let row = sqlx::query_as::<_, InternshipRow>(
-            "INSERT INTO internships (id, student_id, offer_id, status, message) VALUES ($1, $2, $3, $4, $5) RETURNING id, student_id, offer_id, status, message",
+            "INSERT INTO internships (id, student_id, offer_id, status, message, reasons) VALUES ($1, $2, $3, $4, $5, $6) RETURNING id, student_id, offer_id, status, message, reasons",
         )
         .bind(internship.id)
         .bind(internship.student_id)
         .bind(internship.offer_id)
         .bind(&status_str)
         .bind(&internship.message)
-        .fetch_one(&self.pool)
+        .bind(Json(&internship.reasons))
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db) if db.is_unique_violation() => {
+                DomainError::Conflict(format!(
+                    "Student {} has already applied to offer {}",
+                    internship.student_id, internship.offer_id
+                ))
+            }
+            e => DomainError::InfrastructureError(e.to_string()),
+        })?;
+
+        sqlx::query(
+            "INSERT INTO internship_status_history (internship_id, from_status, to_status, message) VALUES ($1, NULL, $2, $3)",
+        )
+        .bind(internship.id)
+        .bind(&status_str)
+        .bind(&internship.message)
+        .execute(&mut *tx)
         .await
         .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
         Internship::try_from(row)
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Internship, DomainError> {
         let row = sqlx::query_as::<_, InternshipRow>(
-            "SELECT id, student_id, offer_id, status, message FROM internships WHERE id = $1",
+            "SELECT id, student_id, offer_id, status, message, reasons FROM internships WHERE id = $1",
         )
         .bind(id)
         .fetch_optional(&self.pool)
@@ -73,4 +142,155 @@ impl InternshipRepository for PostgresInternshipRepository {
 
         Internship::try_from(row)
     }
+
+    async fn find_by_student(
+        &self,
+        student_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        let filter = InternshipFilter {
+            student_id: Some(student_id),
+            ..Default::default()
+        };
+        self.search(&filter, page).await
+    }
+
+    async fn find_by_student_and_offer(
+        &self,
+        student_id: Uuid,
+        offer_id: Uuid,
+    ) -> Result<Option<Internship>, DomainError> {
+        let row = sqlx::query_as::<_, InternshipRow>(
+            "SELECT id, student_id, offer_id, status, message, reasons FROM internships WHERE student_id = $1 AND offer_id = $2 AND status NOT IN ('Withdrawn', 'Declined')",
+        )
+        .bind(student_id)
+        .bind(offer_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        row.map(Internship::try_from).transpose()
+    }
+
+    async fn find_by_offer(
+        &self,
+        offer_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        let filter = InternshipFilter {
+            offer_id: Some(offer_id),
+            ..Default::default()
+        };
+        self.search(&filter, page).await
+    }
+
+    async fn search(
+        &self,
+        filter: &InternshipFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        // Internships are only sortable by id
+        let (op, order) = match page.sort.direction {
+            SortDirection::Asc => (">", "ASC"),
+            SortDirection::Desc => ("<", "DESC"),
+        };
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT id, student_id, offer_id, status, message, reasons FROM internships WHERE TRUE",
+        );
+        if let Some(student_id) = filter.student_id {
+            query.push(" AND student_id = ").push_bind(student_id);
+        }
+        if let Some(offer_id) = filter.offer_id {
+            query.push(" AND offer_id = ").push_bind(offer_id);
+        }
+        if let Some(ref status) = filter.status {
+            query.push(" AND status = ").push_bind(status.to_string());
+        }
+        if let Some(ref cursor) = page.cursor {
+            query.push(format!(" AND id {} ", op)).push_bind(cursor.id);
+        }
+        query
+            .push(format!(" ORDER BY id {} LIMIT ", order))
+            .push_bind(i64::from(page.limit) + 1);
+
+        let rows = query
+            .build_query_as::<InternshipRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        let internships = rows
+            .into_iter()
+            .map(Internship::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Page::from_overfetch(internships, page))
+    }
+
+    async fn update_status(
+        &self,
+        change: &InternshipStatusChange,
+    ) -> Result<Internship, DomainError> {
+        let from_str = change.from.as_ref().map(|s| s.to_string());
+        let to_str = change.to.to_string();
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // Conditional on the current status so concurrent reviews cannot both win
+        let row = sqlx::query_as::<_, InternshipRow>(
+            "UPDATE internships SET status = $2, message = $3 WHERE id = $1 AND status IS NOT DISTINCT FROM $4 RETURNING id, student_id, offer_id, status, message, reasons",
+        )
+        .bind(change.internship_id)
+        .bind(&to_str)
+        .bind(&change.message)
+        .bind(&from_str)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        .ok_or_else(|| {
+            DomainError::ValidationError(format!(
+                "Internship {} is no longer in status {}",
+                change.internship_id,
+                from_str.as_deref().unwrap_or("none")
+            ))
+        })?;
+
+        sqlx::query(
+            "INSERT INTO internship_status_history (internship_id, from_status, to_status, message, changed_at) VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(change.internship_id)
+        .bind(&from_str)
+        .bind(&to_str)
+        .bind(&change.message)
+        .bind(change.changed_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Internship::try_from(row)
+    }
+
+    async fn find_history(
+        &self,
+        internship_id: Uuid,
+    ) -> Result<Vec<InternshipStatusChange>, DomainError> {
+        let rows = sqlx::query_as::<_, StatusChangeRow>(
+            "SELECT internship_id, from_status, to_status, message, changed_at FROM internship_status_history WHERE internship_id = $1 ORDER BY changed_at, id",
+        )
+        .bind(internship_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        rows.into_iter()
+            .map(InternshipStatusChange::try_from)
+            .collect()
+    }
 }
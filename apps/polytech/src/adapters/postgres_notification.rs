@@ -1,8 +1,9 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
 use zukmove_core::domain::entities::notification::Notification;
+use zukmove_core::domain::pagination::{Page, PageRequest, SortDirection};
 use zukmove_core::domain::ports::{DomainError, NotificationRepository};
 
 pub struct PostgresNotificationRepository {
@@ -24,6 +25,7 @@ struct NotificationRow {
     offer_id: Uuid,
     message: String,
     read: bool,
+    stale: bool,
 }
 
 impl From<NotificationRow> for Notification {
@@ -35,6 +37,7 @@ impl From<NotificationRow> for Notification {
             offer_id: row.offer_id,
             message: row.message,
             read: row.read,
+            stale: row.stale,
         }
     }
 }
@@ -43,7 +46,7 @@ impl From<NotificationRow> for Notification {
 impl NotificationRepository for PostgresNotificationRepository {
     async fn save(&self, notification: &Notification) -> Result<Notification, DomainError> {
         sqlx::query(
-            "INSERT INTO notifications (id, student_id, type, offer_id, message, read) VALUES ($1, $2, $3, $4, $5, $6)",
+            "INSERT INTO notifications (id, student_id, type, offer_id, message, read, stale) VALUES ($1, $2, $3, $4, $5, $6, $7)",
         )
         .bind(notification.id)
         .bind(notification.student_id)
@@ -51,6 +54,7 @@ impl NotificationRepository for PostgresNotificationRepository {
         .bind(notification.offer_id)
         .bind(&notification.message)
         .bind(notification.read)
+        .bind(notification.stale)
         .execute(&self.pool)
         .await
         .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
@@ -58,16 +62,38 @@ impl NotificationRepository for PostgresNotificationRepository {
         Ok(notification.clone())
     }
 
-    async fn find_by_student_id(&self, student_id: Uuid) -> Result<Vec<Notification>, DomainError> {
-        let rows = sqlx::query_as::<_, NotificationRow>(
-            "SELECT id, student_id, type, offer_id, message, read FROM notifications WHERE student_id = $1 ORDER BY id DESC",
-        )
-        .bind(student_id)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+    async fn find_by_student_id(
+        &self,
+        student_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Notification>, DomainError> {
+        // Notifications are only sortable by id
+        let (op, order) = match page.sort.direction {
+            SortDirection::Asc => (">", "ASC"),
+            SortDirection::Desc => ("<", "DESC"),
+        };
+
+        let mut query: QueryBuilder<Postgres> = QueryBuilder::new(
+            "SELECT id, student_id, type, offer_id, message, read, stale FROM notifications WHERE student_id = ",
+        );
+        query.push_bind(student_id);
+        if let Some(ref cursor) = page.cursor {
+            query.push(format!(" AND id {} ", op)).push_bind(cursor.id);
+        }
+        query
+            .push(format!(" ORDER BY id {} LIMIT ", order))
+            .push_bind(i64::from(page.limit) + 1);
+
+        let rows = query
+            .build_query_as::<NotificationRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
-        Ok(rows.into_iter().map(Into::into).collect())
+        Ok(Page::from_overfetch(
+            rows.into_iter().map(Into::into).collect(),
+            page,
+        ))
     }
 
     async fn mark_as_read(&self, id: Uuid) -> Result<(), DomainError> {
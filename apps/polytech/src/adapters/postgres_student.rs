@@ -1,16 +1,34 @@
 use async_trait::async_trait;
-use sqlx::{FromRow, PgPool};
+use sqlx::types::Json;
+use sqlx::{FromRow, PgPool, Postgres, QueryBuilder};
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::student::Student;
+use zukmove_core::domain::entities::file::StoredFile;
+use zukmove_core::domain::entities::internship::Internship;
+use zukmove_core::domain::entities::offer::LanguageRequirement;
+use zukmove_core::domain::entities::student::{ErasedRecords, Student, StudentFilter};
+use zukmove_core::domain::normalize::canonical_key;
+use zukmove_core::domain::pagination::{Page, PageRequest, SortDirection, SortKey};
 use zukmove_core::domain::ports::{DomainError, StudentRepository};
 
+use super::postgres_internship::InternshipRow;
+
+/// Columns read into a `StudentRow`, in every query.
+const COLUMNS: &str =
+    "id, firstname, name, domain, email, phone, study_year, languages, cv, version";
+
 #[derive(Debug, FromRow)]
 struct StudentRow {
     id: Uuid,
     firstname: String,
     name: String,
     domain: String,
+    email: Option<String>,
+    phone: Option<String>,
+    study_year: Option<i16>,
+    languages: Json<Vec<LanguageRequirement>>,
+    cv: Option<Json<StoredFile>>,
+    version: i64,
 }
 
 impl From<StudentRow> for Student {
@@ -20,10 +38,24 @@ impl From<StudentRow> for Student {
             firstname: row.firstname,
             name: row.name,
             domain: row.domain,
+            email: row.email,
+            phone: row.phone,
+            study_year: row.study_year.map(|year| year as u8),
+            languages: row.languages.0,
+            cv: row.cv.map(|cv| cv.0),
+            version: row.version as u64,
         }
     }
 }
 
+/// Escapes the LIKE wildcards of a user-supplied prefix (backslash is Postgres' default escape).
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
 pub struct PostgresStudentRepository {
     pool: PgPool,
 }
@@ -37,13 +69,23 @@ impl PostgresStudentRepository {
 #[async_trait]
 impl StudentRepository for PostgresStudentRepository {
     async fn save(&self, student: &Student) -> Result<Student, DomainError> {
-        let row = sqlx::query_as::<_, StudentRow>(
-            "INSERT INTO students (id, firstname, name, domain) VALUES ($1, $2, $3, $4) RETURNING id, firstname, name, domain",
-        )
+        let row = sqlx::query_as::<_, StudentRow>(&format!(
+            "INSERT INTO students (id, firstname, name, domain, domain_key, email, phone, study_year, languages, cv, version, firstname_key, name_key) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) RETURNING {}",
+            COLUMNS
+        ))
         .bind(student.id)
         .bind(&student.firstname)
         .bind(&student.name)
         .bind(&student.domain)
+        .bind(canonical_key(&student.domain))
+        .bind(&student.email)
+        .bind(&student.phone)
+        .bind(student.study_year.map(i16::from))
+        .bind(Json(&student.languages))
+        .bind(student.cv.as_ref().map(Json))
+        .bind(student.version as i64)
+        .bind(canonical_key(&student.firstname))
+        .bind(canonical_key(&student.name))
         .fetch_one(&self.pool)
         .await
         .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
@@ -52,9 +94,10 @@ impl StudentRepository for PostgresStudentRepository {
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Student, DomainError> {
-        let row = sqlx::query_as::<_, StudentRow>(
-            "SELECT id, firstname, name, domain FROM students WHERE id = $1",
-        )
+        let row = sqlx::query_as::<_, StudentRow>(&format!(
+            "SELECT {} FROM students WHERE id = $1",
+            COLUMNS
+        ))
         .bind(id)
         .fetch_optional(&self.pool)
         .await
@@ -64,47 +107,160 @@ impl StudentRepository for PostgresStudentRepository {
         Ok(row.into())
     }
 
-    async fn find_by_domain(&self, domain: &str) -> Result<Vec<Student>, DomainError> {
-        let rows = sqlx::query_as::<_, StudentRow>(
-            "SELECT id, firstname, name, domain FROM students WHERE domain = $1",
-        )
-        .bind(domain)
-        .fetch_all(&self.pool)
-        .await
-        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+    async fn search(
+        &self,
+        filter: &StudentFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Student>, DomainError> {
+        let column = match page.sort.field.as_str() {
+            "firstname" => "firstname",
+            _ => "name",
+        };
+        let (op, order) = match page.sort.direction {
+            SortDirection::Asc => (">", "ASC"),
+            SortDirection::Desc => ("<", "DESC"),
+        };
 
-        Ok(rows.into_iter().map(|r| r.into()).collect())
+        let mut query: QueryBuilder<Postgres> =
+            QueryBuilder::new(format!("SELECT {} FROM students WHERE TRUE", COLUMNS));
+        if let Some(ref name) = filter.name {
+            let pattern = format!("{}%", escape_like(&canonical_key(name)));
+            query
+                .push(" AND (name_key LIKE ")
+                .push_bind(pattern.clone())
+                .push(" OR firstname_key LIKE ")
+                .push_bind(pattern)
+                .push(")");
+        }
+        if let Some(ref domain) = filter.domain {
+            query
+                .push(" AND domain_key = ")
+                .push_bind(canonical_key(domain));
+        }
+        if let Some(year) = filter.study_year {
+            query.push(" AND study_year = ").push_bind(i16::from(year));
+        }
+        if let Some(ref cursor) = page.cursor {
+            let SortKey::Text(ref key) = cursor.key else {
+                return Err(DomainError::ValidationError("Invalid cursor".to_string()));
+            };
+            query
+                .push(format!(" AND ({}, id) {} (", column, op))
+                .push_bind(key.clone())
+                .push(", ")
+                .push_bind(cursor.id)
+                .push(")");
+        }
+        query
+            .push(format!(
+                " ORDER BY {} {}, id {} LIMIT ",
+                column, order, order
+            ))
+            .push_bind(i64::from(page.limit) + 1);
+
+        let rows = query
+            .build_query_as::<StudentRow>()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(Page::from_overfetch(
+            rows.into_iter().map(Student::from).collect(),
+            page,
+        ))
     }
 
     async fn update(&self, student: &Student) -> Result<Student, DomainError> {
-        let row = sqlx::query_as::<_, StudentRow>(
-            "UPDATE students SET firstname = $2, name = $3, domain = $4 WHERE id = $1 RETURNING id, firstname, name, domain",
-        )
+        let row = sqlx::query_as::<_, StudentRow>(&format!(
+            "UPDATE students SET firstname = $2, name = $3, domain = $4, domain_key = $5, email = $6, phone = $7, study_year = $8, languages = $9, cv = $10, firstname_key = $12, name_key = $13, version = version + 1 WHERE id = $1 AND version = $11 RETURNING {}",
+            COLUMNS
+        ))
         .bind(student.id)
         .bind(&student.firstname)
         .bind(&student.name)
         .bind(&student.domain)
+        .bind(canonical_key(&student.domain))
+        .bind(&student.email)
+        .bind(&student.phone)
+        .bind(student.study_year.map(i16::from))
+        .bind(Json(&student.languages))
+        .bind(student.cv.as_ref().map(Json))
+        .bind(student.version as i64)
+        .bind(canonical_key(&student.firstname))
+        .bind(canonical_key(&student.name))
         .fetch_optional(&self.pool)
         .await
-        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
-        .ok_or_else(|| DomainError::NotFound(format!("Student with id {} not found", student.id)))?;
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
-        Ok(row.into())
+        match row {
+            Some(row) => Ok(row.into()),
+            // No row matched: either the student is gone or its version moved on
+            None => match self.find_by_id(student.id).await {
+                Ok(_) => Err(DomainError::PreconditionFailed(format!(
+                    "Student with id {} was modified since version {}",
+                    student.id, student.version
+                ))),
+                Err(e) => Err(e),
+            },
+        }
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
-        let result = sqlx::query("DELETE FROM students WHERE id = $1")
+    async fn erase(&self, id: Uuid) -> Result<ErasedRecords, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        // Locking the student blocks new internships and notifications referencing it
+        sqlx::query("SELECT id FROM students WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            .ok_or_else(|| DomainError::NotFound(format!("Student with id {} not found", id)))?;
+
+        let status_changes = sqlx::query(
+            "DELETE FROM internship_status_history WHERE internship_id IN (SELECT id FROM internships WHERE student_id = $1)",
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        .rows_affected();
+
+        let internships = sqlx::query_as::<_, InternshipRow>(
+            "DELETE FROM internships WHERE student_id = $1 RETURNING id, student_id, offer_id, status, message, reasons",
+        )
+        .bind(id)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+        .into_iter()
+        .map(Internship::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        let notifications = sqlx::query("DELETE FROM notifications WHERE student_id = $1")
             .bind(id)
-            .execute(&self.pool)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?
+            .rows_affected();
+
+        sqlx::query("DELETE FROM students WHERE id = $1")
+            .bind(id)
+            .execute(&mut *tx)
             .await
             .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
 
-        if result.rows_affected() == 0 {
-            return Err(DomainError::NotFound(format!(
-                "Student with id {} not found",
-                id
-            )));
-        }
-        Ok(())
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::InfrastructureError(e.to_string()))?;
+
+        Ok(ErasedRecords {
+            internships,
+            status_changes,
+            notifications,
+        })
     }
 }
@@ -1,9 +1,14 @@
 use actix_web::{HttpResponse, web};
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::internship::{CreateInternshipRequest, Internship};
+use zukmove_core::domain::entities::internship::{
+    CreateInternshipRequest, Internship, InternshipFilter, InternshipStatus,
+    InternshipStatusChange, UpdateInternshipStatusRequest,
+};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 
 use crate::AppState;
+use crate::routes::offer::PageQuery;
 use crate::routes::student::domain_error_to_response;
 
 #[utoipa::path(
@@ -11,8 +16,9 @@ use crate::routes::student::domain_error_to_response;
     path = "/internship",
     request_body = CreateInternshipRequest,
     responses(
-        (status = 201, description = "Internship status created", body = Internship),
+        (status = 201, description = "Internship status created, or the student's existing application to this offer", body = Internship),
         (status = 404, description = "Student or Offer not found"),
+        (status = 409, description = "A concurrent application to the same offer was created first"),
         (status = 500, description = "Internal error")
     )
 )]
@@ -26,6 +32,82 @@ pub async fn create_internship(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/internship",
+    params(
+        ("student_id" = Option<Uuid>, Query, description = "Filter by student (optional)"),
+        ("offer_id" = Option<Uuid>, Query, description = "Filter by offer (optional)"),
+        ("status" = Option<InternshipStatus>, Query, description = "Filter by status (optional)"),
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor of the previous page"),
+        ("sort" = Option<String>, Query, description = "id; prefix with '-' for descending order")
+    ),
+    responses(
+        (status = 200, description = "Page of matching internships", body = Page<Internship>),
+        (status = 400, description = "Invalid filter or pagination parameters"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_internships(
+    state: web::Data<AppState>,
+    filter: web::Query<InternshipFilter>,
+    query: web::Query<PageQuery>,
+) -> HttpResponse {
+    let page = match PageRequest::parse::<Internship>(
+        query.limit,
+        query.cursor.as_deref(),
+        query.sort.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return domain_error_to_response(e),
+    };
+    match state.internship_service.search(&filter, &page).await {
+        Ok(internships) => HttpResponse::Ok().json(internships),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/student/{id}/internships",
+    params(
+        ("id" = Uuid, Path, description = "Student ID"),
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor of the previous page"),
+        ("sort" = Option<String>, Query, description = "id; prefix with '-' for descending order")
+    ),
+    responses(
+        (status = 200, description = "Page of the student's internships", body = Page<Internship>),
+        (status = 400, description = "Invalid pagination parameters"),
+        (status = 404, description = "Student not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_student_internships(
+    state: web::Data<AppState>,
+    path: web::Path<Uuid>,
+    query: web::Query<PageQuery>,
+) -> HttpResponse {
+    let student_id = path.into_inner();
+    let page = match PageRequest::parse::<Internship>(
+        query.limit,
+        query.cursor.as_deref(),
+        query.sort.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return domain_error_to_response(e),
+    };
+    match state
+        .internship_service
+        .find_by_student(student_id, &page)
+        .await
+    {
+        Ok(internships) => HttpResponse::Ok().json(internships),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/internship/{id}",
@@ -45,3 +127,57 @@ pub async fn get_internship(state: web::Data<AppState>, path: web::Path<Uuid>) -
         Err(e) => domain_error_to_response(e),
     }
 }
+
+#[utoipa::path(
+    put,
+    path = "/internship/{id}/status",
+    request_body = UpdateInternshipStatusRequest,
+    params(
+        ("id" = Uuid, Path, description = "Internship ID")
+    ),
+    responses(
+        (status = 200, description = "Internship status updated; accepting it reserves a seat on the offer", body = Internship),
+        (status = 400, description = "Invalid status transition"),
+        (status = 404, description = "Internship not found"),
+        (status = 409, description = "Offer full: the application cannot be accepted"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn update_internship_status(
+    state: web::Data<AppState>,
+    path: web::Path<Uuid>,
+    body: web::Json<UpdateInternshipStatusRequest>,
+) -> HttpResponse {
+    let id = path.into_inner();
+    match state
+        .internship_service
+        .update_status(id, body.into_inner())
+        .await
+    {
+        Ok(internship) => HttpResponse::Ok().json(internship),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/internship/{id}/history",
+    params(
+        ("id" = Uuid, Path, description = "Internship ID")
+    ),
+    responses(
+        (status = 200, description = "Status history, oldest first", body = [InternshipStatusChange]),
+        (status = 404, description = "Internship not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_internship_history(
+    state: web::Data<AppState>,
+    path: web::Path<Uuid>,
+) -> HttpResponse {
+    let id = path.into_inner();
+    match state.internship_service.find_history(id).await {
+        Ok(history) => HttpResponse::Ok().json(history),
+        Err(e) => domain_error_to_response(e),
+    }
+}
@@ -16,7 +16,7 @@ pub async fn get_news(
     query: web::Query<NewsQuery>,
 ) -> HttpResponse {
     let limit = query.limit.unwrap_or(10);
-    let client = grpc_client.lock().unwrap();
+    let client = grpc_client.lock().unwrap().clone();
 
     let result = if let Some(ref city) = query.city {
         client.get_latest_news_in_city(city, limit).await
@@ -1,17 +1,38 @@
 use actix_web::{HttpResponse, web};
 use uuid::Uuid;
 
-use zukmove_core::domain::ports::NotificationRepository;
+use zukmove_core::domain::entities::notification::Notification;
+use zukmove_core::domain::pagination::PageRequest;
 
 use crate::AppState;
 use crate::routes::student::domain_error_to_response;
 
+#[derive(serde::Deserialize)]
+pub struct NotificationQuery {
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+    pub sort: Option<String>,
+}
+
 pub async fn get_notifications(
     state: web::Data<AppState>,
     path: web::Path<Uuid>,
+    query: web::Query<NotificationQuery>,
 ) -> HttpResponse {
     let student_id = path.into_inner();
-    match state.notification_repo.find_by_student_id(student_id).await {
+    let page = match PageRequest::parse::<Notification>(
+        query.limit,
+        query.cursor.as_deref(),
+        query.sort.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return domain_error_to_response(e),
+    };
+    match state
+        .notification_repo
+        .find_by_student_id(student_id, &page)
+        .await
+    {
         Ok(notifications) => HttpResponse::Ok().json(notifications),
         Err(e) => domain_error_to_response(e),
     }
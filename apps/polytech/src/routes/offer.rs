@@ -2,11 +2,14 @@ use actix_web::{HttpResponse, web};
 
 use crate::AppState;
 use zukmove_core::domain::entities::gateway::AggregatedOffer;
+use zukmove_core::domain::entities::offer::{CefrLevel, Offer, OfferFilter, RemotePolicy};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 
 #[derive(serde::Deserialize)]
-pub struct OfferQuery {
-    pub domain: Option<String>,
-    pub city: Option<String>,
+pub struct PageQuery {
+    pub limit: Option<u32>,
+    pub cursor: Option<String>,
+    pub sort: Option<String>,
 }
 
 #[utoipa::path(
@@ -14,20 +17,45 @@ pub struct OfferQuery {
     path = "/offer",
     params(
         ("domain" = Option<String>, Query, description = "Filter by domain (optional)"),
-        ("city" = Option<String>, Query, description = "Filter by city (optional)")
+        ("city" = Option<String>, Query, description = "Filter by city (optional)"),
+        ("min_salary" = Option<f64>, Query, description = "Minimum monthly salary (optional)"),
+        ("max_salary" = Option<f64>, Query, description = "Maximum monthly salary (optional)"),
+        ("currency" = Option<String>, Query, description = "ISO 4217 currency of the salary bounds (optional, default: base currency of Erasmumu)"),
+        ("start_after" = Option<chrono::NaiveDate>, Query, description = "Internship starts on or after this date (optional)"),
+        ("end_before" = Option<chrono::NaiveDate>, Query, description = "Internship ends on or before this date (optional)"),
+        ("text" = Option<String>, Query, description = "Case-insensitive text contained in the title (optional)"),
+        ("company_id" = Option<uuid::Uuid>, Query, description = "Offers of this company (optional)"),
+        ("skill" = Option<String>, Query, description = "Skill required by the offer (optional)"),
+        ("language" = Option<String>, Query, description = "Language required by the offer (optional)"),
+        ("language_level" = Option<CefrLevel>, Query, description = "With language: offers requiring at most this CEFR level (optional)"),
+        ("remote_policy" = Option<RemotePolicy>, Query, description = "on_site, hybrid or remote (optional)"),
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor of the previous page"),
+        ("sort" = Option<String>, Query, description = "start_date, title, city or salary; prefix with '-' for descending order")
     ),
     responses(
-        (status = 200, description = "List of aggregated offers", body = Vec<AggregatedOffer>),
+        (status = 200, description = "Page of aggregated offers, with their company summary", body = Page<AggregatedOffer>),
+        (status = 400, description = "Invalid filter or pagination parameters"),
         (status = 500, description = "Internal error")
     )
 )]
-pub async fn get_offers(state: web::Data<AppState>, query: web::Query<OfferQuery>) -> HttpResponse {
-    let domain = query.domain.clone();
-    let city = query.city.clone();
+pub async fn get_offers(
+    state: web::Data<AppState>,
+    filter: web::Query<OfferFilter>,
+    query: web::Query<PageQuery>,
+) -> HttpResponse {
+    let page = match PageRequest::parse::<Offer>(
+        query.limit,
+        query.cursor.as_deref(),
+        query.sort.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return crate::routes::student::domain_error_to_response(e),
+    };
 
     match state
         .internship_service
-        .get_aggregated_offers(domain, city)
+        .get_aggregated_offers(&filter, &page)
         .await
     {
         Ok(offers) => HttpResponse::Ok().json(offers),
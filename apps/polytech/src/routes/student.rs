@@ -1,12 +1,21 @@
-use actix_web::{HttpResponse, web};
+use actix_web::http::header;
+use actix_web::{HttpRequest, HttpResponse, web};
 use uuid::Uuid;
 
+use zukmove_core::domain::concurrency::{IfMatch, etag};
+use zukmove_core::domain::entities::file::StoredFile;
+use zukmove_core::domain::entities::internship::{Internship, InternshipFilter};
+use zukmove_core::domain::entities::notification::Notification;
 use zukmove_core::domain::entities::student::{
-    CreateStudentRequest, Student, UpdateStudentRequest,
+    CreateStudentRequest, ErasureReport, Student, StudentExport, StudentFilter,
+    UpdateStudentRequest,
 };
+use zukmove_core::domain::pagination::{Page, PageRequest};
 use zukmove_core::domain::ports::DomainError;
+use zukmove_core::domain::validation::{Validate, ValidationFailure};
 
 use crate::AppState;
+use crate::routes::offer::PageQuery;
 
 #[utoipa::path(
     post,
@@ -14,7 +23,8 @@ use crate::AppState;
     request_body = CreateStudentRequest,
     responses(
         (status = 201, description = "Student created", body = Student),
-        (status = 400, description = "Validation error"),
+        (status = 400, description = "Domain missing from the catalog"),
+        (status = 422, description = "Invalid fields, all listed in `fields`", body = ValidationFailure),
         (status = 500, description = "Internal error")
     )
 )]
@@ -22,44 +32,84 @@ pub async fn create_student(
     state: web::Data<AppState>,
     body: web::Json<CreateStudentRequest>,
 ) -> HttpResponse {
+    if let Err(e) = body.validate() {
+        return domain_error_to_response(e);
+    }
+    let domain = match state
+        .domain_client
+        .get_taxonomy()
+        .await
+        .and_then(|t| t.canonical_name(&body.domain))
+    {
+        Ok(domain) => domain,
+        Err(e) => return domain_error_to_response(e),
+    };
+
     let student = Student {
         id: Uuid::new_v4(),
         firstname: body.firstname.clone(),
         name: body.name.clone(),
-        domain: body.domain.clone(),
+        domain,
+        email: body.email.clone(),
+        phone: body.phone.clone(),
+        study_year: body.study_year,
+        languages: body.languages.clone(),
+        cv: None,
+        version: 1,
     };
 
     match state.student_repo.save(&student).await {
         Ok(s) => {
-            // Publish student.registered event
-            if let Some(ref channel) = state.rabbitmq_channel {
-                let event = serde_json::json!({
-                    "studentId": s.id.to_string(),
-                    "name": format!("{} {}", s.firstname, s.name),
-                    "domain": s.domain,
-                    "createdAt": chrono::Utc::now().format("%Y-%m-%d").to_string(),
-                });
-                if let Ok(payload) = serde_json::to_vec(&event) {
-                    let _ = channel
-                        .basic_publish(
-                            "zukmove.events",
-                            "student.registered",
-                            lapin::options::BasicPublishOptions::default(),
-                            &payload,
-                            lapin::BasicProperties::default()
-                                .with_content_type("application/json".into())
-                                .with_delivery_mode(2),
-                        )
-                        .await;
-                    log::info!("Published student.registered event for {}", s.id);
-                }
-            }
+            let event = serde_json::json!({
+                "studentId": s.id.to_string(),
+                "name": format!("{} {}", s.firstname, s.name),
+                "domain": s.domain,
+                "email": s.email,
+                "phone": s.phone,
+                "createdAt": chrono::Utc::now().format("%Y-%m-%d").to_string(),
+            });
+            publish_student_event(&state, "student.registered", &event).await;
             HttpResponse::Created().json(s)
         }
         Err(e) => domain_error_to_response(e),
     }
 }
 
+/// Publishes a student event on the `zukmove.events` exchange; returns whether it was sent.
+async fn publish_student_event(
+    state: &AppState,
+    routing_key: &str,
+    event: &serde_json::Value,
+) -> bool {
+    let Some(ref channel) = state.rabbitmq_channel else {
+        return false;
+    };
+    let Ok(payload) = serde_json::to_vec(event) else {
+        return false;
+    };
+    match channel
+        .basic_publish(
+            "zukmove.events",
+            routing_key,
+            lapin::options::BasicPublishOptions::default(),
+            &payload,
+            lapin::BasicProperties::default()
+                .with_content_type("application/json".into())
+                .with_delivery_mode(2),
+        )
+        .await
+    {
+        Ok(_) => {
+            log::info!("Published {} event for {}", routing_key, event["studentId"]);
+            true
+        }
+        Err(e) => {
+            log::error!("Failed to publish {} event: {}", routing_key, e);
+            false
+        }
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/student/{id}",
@@ -67,7 +117,7 @@ pub async fn create_student(
         ("id" = Uuid, Path, description = "Student ID")
     ),
     responses(
-        (status = 200, description = "Student found", body = Student),
+        (status = 200, description = "Student found, with its version as ETag", body = Student),
         (status = 404, description = "Student not found"),
         (status = 500, description = "Internal error")
     )
@@ -75,41 +125,57 @@ pub async fn create_student(
 pub async fn get_student(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
     let id = path.into_inner();
     match state.student_repo.find_by_id(id).await {
-        Ok(s) => HttpResponse::Ok().json(s),
+        Ok(s) => versioned(&s, s.version),
         Err(e) => domain_error_to_response(e),
     }
 }
 
-#[derive(serde::Deserialize)]
-pub struct StudentQuery {
-    pub domain: Option<String>,
-}
-
 #[utoipa::path(
     get,
     path = "/student",
     params(
-        ("domain" = inline(Option<String>), Query, description = "Filter by domain")
+        ("name" = Option<String>, Query, description = "Start of the last name or first name, ignoring case and accents (optional)"),
+        ("domain" = Option<String>, Query, description = "Filter by domain or one of its aliases (optional)"),
+        ("study_year" = Option<u8>, Query, description = "Filter by year of study (optional)"),
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor of the previous page"),
+        ("sort" = Option<String>, Query, description = "name or firstname; prefix with '-' for descending order")
     ),
     responses(
-        (status = 200, description = "List of students", body = [Student]),
-        (status = 400, description = "Missing query parameter"),
+        (status = 200, description = "Page of matching students; the whole cohort without filter", body = Page<Student>),
+        (status = 400, description = "Invalid filter or pagination parameters"),
         (status = 500, description = "Internal error")
     )
 )]
 pub async fn list_students(
     state: web::Data<AppState>,
-    query: web::Query<StudentQuery>,
+    filter: web::Query<StudentFilter>,
+    query: web::Query<PageQuery>,
 ) -> HttpResponse {
-    if let Some(ref domain) = query.domain {
-        match state.student_repo.find_by_domain(domain).await {
-            Ok(students) => HttpResponse::Ok().json(students),
-            Err(e) => domain_error_to_response(e),
-        }
-    } else {
-        HttpResponse::BadRequest().json(serde_json::json!({
-            "error": "Query parameter 'domain' is required"
-        }))
+    let page = match PageRequest::parse::<Student>(
+        query.limit,
+        query.cursor.as_deref(),
+        query.sort.as_deref(),
+    ) {
+        Ok(page) => page,
+        Err(e) => return domain_error_to_response(e),
+    };
+
+    let mut filter = filter.into_inner();
+    if let Some(ref domain) = filter.domain {
+        // An alias selects the same students as the domain's name
+        let domain = match state.domain_client.get_taxonomy().await {
+            Ok(taxonomy) => taxonomy
+                .resolve(domain)
+                .map_or_else(|| domain.clone(), |d| d.name.clone()),
+            Err(e) => return domain_error_to_response(e),
+        };
+        filter.domain = Some(domain);
+    }
+
+    match state.student_repo.search(&filter, &page).await {
+        Ok(students) => HttpResponse::Ok().json(students),
+        Err(e) => domain_error_to_response(e),
     }
 }
 
@@ -118,36 +184,73 @@ pub async fn list_students(
     path = "/student/{id}",
     request_body = UpdateStudentRequest,
     params(
-        ("id" = Uuid, Path, description = "Student ID")
+        ("id" = Uuid, Path, description = "Student ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the student as last read; the update is refused if it changed since")
     ),
     responses(
-        (status = 200, description = "Student updated", body = Student),
+        (status = 200, description = "Student updated, with its new ETag", body = Student),
+        (status = 400, description = "Domain missing from the catalog or malformed If-Match"),
         (status = 404, description = "Student not found"),
+        (status = 412, description = "Student modified since the ETag in If-Match, or by a concurrent update"),
+        (status = 422, description = "Invalid fields, all listed in `fields`", body = ValidationFailure),
         (status = 500, description = "Internal error")
     )
 )]
 pub async fn update_student(
     state: web::Data<AppState>,
+    req: HttpRequest,
     path: web::Path<Uuid>,
     body: web::Json<UpdateStudentRequest>,
 ) -> HttpResponse {
+    if let Err(e) = body.validate() {
+        return domain_error_to_response(e);
+    }
     let id = path.into_inner();
+    let condition = match if_match(&req) {
+        Ok(condition) => condition,
+        Err(e) => return domain_error_to_response(e),
+    };
 
     // Retrieve existing student first
     let existing = match state.student_repo.find_by_id(id).await {
         Ok(s) => s,
         Err(e) => return domain_error_to_response(e),
     };
+    if let Some(condition) = condition
+        && let Err(e) = condition.check(existing.version, &format!("Student with id {}", id))
+    {
+        return domain_error_to_response(e);
+    }
+
+    let domain = match body.domain {
+        Some(ref domain) => match state
+            .domain_client
+            .get_taxonomy()
+            .await
+            .and_then(|t| t.canonical_name_for_update(domain, &existing.domain))
+        {
+            Ok(domain) => domain,
+            Err(e) => return domain_error_to_response(e),
+        },
+        None => existing.domain,
+    };
 
     let updated = Student {
         id: existing.id,
         firstname: body.firstname.clone().unwrap_or(existing.firstname),
         name: body.name.clone().unwrap_or(existing.name),
-        domain: body.domain.clone().unwrap_or(existing.domain),
+        domain,
+        email: body.email.clone().unwrap_or(existing.email),
+        phone: body.phone.clone().unwrap_or(existing.phone),
+        study_year: body.study_year.unwrap_or(existing.study_year),
+        languages: body.languages.clone().unwrap_or(existing.languages),
+        cv: existing.cv,
+        // The write only succeeds if nobody updated the student since it was read above
+        version: existing.version,
     };
 
     match state.student_repo.update(&updated).await {
-        Ok(s) => HttpResponse::Ok().json(s),
+        Ok(s) => versioned(&s, s.version),
         Err(e) => domain_error_to_response(e),
     }
 }
@@ -159,19 +262,346 @@ pub async fn update_student(
         ("id" = Uuid, Path, description = "Student ID")
     ),
     responses(
-        (status = 204, description = "Student deleted"),
+        (status = 200, description = "Student erased with their internships, notifications and CV (publishes student.deleted); the report holds no personal data", body = ErasureReport),
         (status = 404, description = "Student not found"),
         (status = 500, description = "Internal error")
     )
 )]
 pub async fn delete_student(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
     let id = path.into_inner();
-    match state.student_repo.delete(id).await {
+    let student = match state.student_repo.find_by_id(id).await {
+        Ok(s) => s,
+        Err(e) => return domain_error_to_response(e),
+    };
+    let erased = match state.student_repo.erase(id).await {
+        Ok(erased) => erased,
+        Err(e) => return domain_error_to_response(e),
+    };
+    let erased_at = chrono::Utc::now();
+
+    // The rows are gone for good: later failures are logged and show in the report
+    let cv_deleted = match student.cv {
+        Some(ref cv) => match state.file_storage.delete(&cv.key).await {
+            Ok(()) => true,
+            Err(e) => {
+                log::error!("Failed to delete the CV of student {}: {}", id, e);
+                false
+            }
+        },
+        None => false,
+    };
+
+    let mut released_seats = Vec::new();
+    for internship in &erased.internships {
+        match state
+            .internship_service
+            .release_erased_seat(internship)
+            .await
+        {
+            Ok(true) => released_seats.push(internship.offer_id),
+            Ok(false) => {}
+            Err(e) => log::error!(
+                "Failed to release the seat of erased internship {} on offer {}: {}",
+                internship.id,
+                internship.offer_id,
+                e
+            ),
+        }
+    }
+
+    let event = serde_json::json!({
+        "studentId": id.to_string(),
+        "deletedAt": erased_at.to_rfc3339(),
+    });
+    let deletion_published = publish_student_event(&state, "student.deleted", &event).await;
+
+    HttpResponse::Ok().json(ErasureReport {
+        student_id: id,
+        erased_at,
+        internships: erased.internships.len() as u64,
+        status_changes: erased.status_changes,
+        notifications: erased.notifications,
+        cv_deleted,
+        released_seats,
+        deletion_published,
+        verified: erasure_verified(&state, &student).await,
+    })
+}
+
+/// Reads every store back: nothing about the student may be left.
+async fn erasure_verified(state: &AppState, student: &Student) -> bool {
+    let student_gone = matches!(
+        state.student_repo.find_by_id(student.id).await,
+        Err(DomainError::NotFound(_))
+    );
+    let internships_gone = state
+        .internship_service
+        .search(
+            &InternshipFilter {
+                student_id: Some(student.id),
+                ..Default::default()
+            },
+            &PageRequest::first::<Internship>(1),
+        )
+        .await
+        .is_ok_and(|page| page.items.is_empty());
+    let notifications_gone = state
+        .notification_repo
+        .find_by_student_id(student.id, &PageRequest::first::<Notification>(1))
+        .await
+        .is_ok_and(|page| page.items.is_empty());
+    let cv_gone = match student.cv {
+        Some(ref cv) => matches!(
+            state.file_storage.get(&cv.key).await,
+            Err(DomainError::NotFound(_))
+        ),
+        None => true,
+    };
+
+    let verified = student_gone && internships_gone && notifications_gone && cv_gone;
+    if !verified {
+        log::error!(
+            "Erasure of student {} incomplete: student gone {}, internships gone {}, notifications gone {}, CV gone {}",
+            student.id,
+            student_gone,
+            internships_gone,
+            notifications_gone,
+            cv_gone
+        );
+    }
+    verified
+}
+
+/// Largest CV accepted by `PUT /student/{id}/cv`.
+pub const MAX_CV_SIZE: usize = 5 * 1024 * 1024;
+
+/// PDF, Word and OpenDocument text files.
+const CV_CONTENT_TYPES: &[&str] = &[
+    "application/pdf",
+    "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+    "application/vnd.oasis.opendocument.text",
+];
+
+#[derive(serde::Deserialize)]
+pub struct CvQuery {
+    pub filename: Option<String>,
+}
+
+#[utoipa::path(
+    put,
+    path = "/student/{id}/cv",
+    request_body(content = Vec<u8>, description = "The CV file, up to 5 MB", content_type = "application/pdf"),
+    params(
+        ("id" = Uuid, Path, description = "Student ID"),
+        ("filename" = Option<String>, Query, description = "Name offered when the CV is downloaded (default: cv)"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the student as last read")
+    ),
+    responses(
+        (status = 200, description = "CV stored, replacing the previous one; the student with its new ETag", body = Student),
+        (status = 400, description = "Empty file or malformed If-Match"),
+        (status = 404, description = "Student not found"),
+        (status = 412, description = "Student modified since the ETag in If-Match"),
+        (status = 413, description = "File larger than 5 MB"),
+        (status = 415, description = "Not a PDF, Word (.docx) or OpenDocument (.odt) file"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn upload_cv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+    query: web::Query<CvQuery>,
+    body: web::Bytes,
+) -> HttpResponse {
+    let id = path.into_inner();
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .map(|value| value.trim().to_lowercase())
+        .unwrap_or_default();
+    if !CV_CONTENT_TYPES.contains(&content_type.as_str()) {
+        return HttpResponse::UnsupportedMediaType().json(serde_json::json!({
+            "error": format!("A CV must be one of {}", CV_CONTENT_TYPES.join(", "))
+        }));
+    }
+    if body.is_empty() {
+        return domain_error_to_response(DomainError::ValidationError(
+            "The CV file is empty".to_string(),
+        ));
+    }
+    let condition = match if_match(&req) {
+        Ok(condition) => condition,
+        Err(e) => return domain_error_to_response(e),
+    };
+
+    let existing = match state.student_repo.find_by_id(id).await {
+        Ok(s) => s,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if let Some(condition) = condition
+        && let Err(e) = condition.check(existing.version, &format!("Student with id {}", id))
+    {
+        return domain_error_to_response(e);
+    }
+
+    // The new file gets its own key: the stored CV stays valid until the student points elsewhere
+    let key = existing.new_cv_key();
+    if let Err(e) = state.file_storage.put(&key, &body).await {
+        return domain_error_to_response(e);
+    }
+    let previous = existing.cv.clone();
+    let cv = StoredFile {
+        key: key.clone(),
+        filename: cv_filename(query.filename.as_deref()),
+        content_type,
+        size: body.len() as u64,
+        uploaded_at: chrono::Utc::now(),
+    };
+    let updated = Student {
+        cv: Some(cv),
+        ..existing
+    };
+    match state.student_repo.update(&updated).await {
+        Ok(s) => {
+            if let Some(previous) = previous {
+                delete_cv_file(&state, id, &previous.key).await;
+            }
+            versioned(&s, s.version)
+        }
+        Err(e) => {
+            delete_cv_file(&state, id, &key).await;
+            domain_error_to_response(e)
+        }
+    }
+}
+
+/// Removes a CV file no student points to any more; a leftover file is only logged.
+async fn delete_cv_file(state: &AppState, id: Uuid, key: &str) {
+    if let Err(e) = state.file_storage.delete(key).await {
+        log::error!("Failed to delete CV file {} of student {}: {}", key, id, e);
+    }
+}
+
+/// Last segment of the given name, so that a client path never reaches the download header.
+fn cv_filename(filename: Option<&str>) -> String {
+    filename
+        .and_then(|name| name.rsplit(['/', '\\']).next())
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(|name| name.chars().take(255).collect())
+        .unwrap_or_else(|| "cv".to_string())
+}
+
+#[utoipa::path(
+    get,
+    path = "/student/{id}/cv",
+    params(
+        ("id" = Uuid, Path, description = "Student ID")
+    ),
+    responses(
+        (status = 200, description = "The CV file, as an attachment with its original name", content_type = "application/octet-stream"),
+        (status = 404, description = "Student not found or without CV"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn download_cv(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    let id = path.into_inner();
+    let cv = match state.student_repo.find_by_id(id).await {
+        Ok(Student { cv: Some(cv), .. }) => cv,
+        Ok(_) => {
+            return domain_error_to_response(DomainError::NotFound(format!(
+                "Student with id {} has no CV",
+                id
+            )));
+        }
+        Err(e) => return domain_error_to_response(e),
+    };
+    match state.file_storage.get(&cv.key).await {
+        Ok(content) => HttpResponse::Ok()
+            .content_type(cv.content_type)
+            .insert_header(header::ContentDisposition::attachment(cv.filename))
+            .body(content),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/student/{id}/cv",
+    params(
+        ("id" = Uuid, Path, description = "Student ID"),
+        ("If-Match" = Option<String>, Header, description = "ETag of the student as last read")
+    ),
+    responses(
+        (status = 204, description = "CV deleted"),
+        (status = 400, description = "Malformed If-Match"),
+        (status = 404, description = "Student not found or without CV"),
+        (status = 412, description = "Student modified since the ETag in If-Match"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn delete_cv(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<Uuid>,
+) -> HttpResponse {
+    let id = path.into_inner();
+    let condition = match if_match(&req) {
+        Ok(condition) => condition,
+        Err(e) => return domain_error_to_response(e),
+    };
+    let existing = match state.student_repo.find_by_id(id).await {
+        Ok(s) => s,
+        Err(e) => return domain_error_to_response(e),
+    };
+    if let Some(condition) = condition
+        && let Err(e) = condition.check(existing.version, &format!("Student with id {}", id))
+    {
+        return domain_error_to_response(e);
+    }
+    let Some(ref cv) = existing.cv else {
+        return domain_error_to_response(DomainError::NotFound(format!(
+            "Student with id {} has no CV",
+            id
+        )));
+    };
+    let key = cv.key.clone();
+
+    let updated = Student {
+        cv: None,
+        ..existing
+    };
+    if let Err(e) = state.student_repo.update(&updated).await {
+        return domain_error_to_response(e);
+    }
+    match state.file_storage.delete(&key).await {
         Ok(()) => HttpResponse::NoContent().finish(),
         Err(e) => domain_error_to_response(e),
     }
 }
 
+/// Reads the `If-Match` header of a conditional update, if any.
+fn if_match(req: &HttpRequest) -> Result<Option<IfMatch>, DomainError> {
+    req.headers()
+        .get(header::IF_MATCH)
+        .map(|value| {
+            value
+                .to_str()
+                .map_err(|_| DomainError::ValidationError("Invalid If-Match header".to_string()))
+                .and_then(IfMatch::parse)
+        })
+        .transpose()
+}
+
+/// 200 response carrying the version of the resource as its `ETag`.
+fn versioned<T: serde::Serialize>(body: &T, version: u64) -> HttpResponse {
+    HttpResponse::Ok()
+        .insert_header((header::ETAG, etag(version)))
+        .json(body)
+}
+
 pub fn domain_error_to_response(err: DomainError) -> HttpResponse {
     match err {
         DomainError::NotFound(msg) => HttpResponse::NotFound().json(serde_json::json!({
@@ -180,6 +610,17 @@ pub fn domain_error_to_response(err: DomainError) -> HttpResponse {
         DomainError::ValidationError(msg) => HttpResponse::BadRequest().json(serde_json::json!({
             "error": msg
         })),
+        DomainError::InvalidFields(fields) => {
+            HttpResponse::UnprocessableEntity().json(ValidationFailure::new(fields))
+        }
+        DomainError::Conflict(msg) => HttpResponse::Conflict().json(serde_json::json!({
+            "error": msg
+        })),
+        DomainError::PreconditionFailed(msg) => {
+            HttpResponse::PreconditionFailed().json(serde_json::json!({
+                "error": msg
+            }))
+        }
         DomainError::InfrastructureError(msg) => {
             log::error!("Infrastructure error: {}", msg);
             HttpResponse::InternalServerError().json(serde_json::json!({
@@ -189,6 +630,91 @@ pub fn domain_error_to_response(err: DomainError) -> HttpResponse {
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/student/{id}/export",
+    params(
+        ("id" = Uuid, Path, description = "Student ID")
+    ),
+    responses(
+        (status = 200, description = "Every record held about the student, La Poste preferences included, as one JSON document", body = StudentExport),
+        (status = 404, description = "Student not found"),
+        (status = 500, description = "Internal error, or La Poste unreachable: an export is never partial")
+    )
+)]
+pub async fn export_student(state: web::Data<AppState>, path: web::Path<Uuid>) -> HttpResponse {
+    match collect_student_data(&state, path.into_inner()).await {
+        Ok(export) => HttpResponse::Ok()
+            .insert_header(header::ContentDisposition::attachment(format!(
+                "student-{}.json",
+                export.student.id
+            )))
+            .json(export),
+        Err(e) => domain_error_to_response(e),
+    }
+}
+
+async fn collect_student_data(state: &AppState, id: Uuid) -> Result<StudentExport, DomainError> {
+    let student = state.student_repo.find_by_id(id).await?;
+
+    let mut internships = Vec::new();
+    let filter = InternshipFilter {
+        student_id: Some(id),
+        ..Default::default()
+    };
+    let mut page = PageRequest::first::<Internship>(PageRequest::MAX_LIMIT);
+    loop {
+        let batch = state.internship_service.search(&filter, &page).await?;
+        internships.extend(batch.items);
+        match batch.next_cursor {
+            Some(cursor) => {
+                page = PageRequest::parse::<Internship>(
+                    Some(PageRequest::MAX_LIMIT),
+                    Some(&cursor),
+                    None,
+                )?
+            }
+            None => break,
+        }
+    }
+
+    let mut internship_history = Vec::new();
+    for internship in &internships {
+        internship_history.extend(state.internship_service.find_history(internship.id).await?);
+    }
+
+    let mut notifications = Vec::new();
+    let mut page = PageRequest::first::<Notification>(PageRequest::MAX_LIMIT);
+    loop {
+        let batch = state
+            .notification_repo
+            .find_by_student_id(id, &page)
+            .await?;
+        notifications.extend(batch.items);
+        match batch.next_cursor {
+            Some(cursor) => {
+                page = PageRequest::parse::<Notification>(
+                    Some(PageRequest::MAX_LIMIT),
+                    Some(&cursor),
+                    None,
+                )?
+            }
+            None => break,
+        }
+    }
+
+    let subscriber = state.subscriber_client.get_subscriber(id).await?;
+
+    Ok(StudentExport {
+        exported_at: chrono::Utc::now(),
+        student,
+        internships,
+        internship_history,
+        notifications,
+        subscriber,
+    })
+}
+
 #[utoipa::path(
     get,
     path = "/student/{id}/recommended-offers",
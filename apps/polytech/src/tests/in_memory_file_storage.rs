@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+
+use zukmove_core::domain::ports::{DomainError, FileStorage};
+
+/// In-memory implementation of FileStorage for testing.
+pub struct InMemoryFileStorage {
+    files: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl InMemoryFileStorage {
+    pub fn new() -> Self {
+        Self {
+            files: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl FileStorage for InMemoryFileStorage {
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), DomainError> {
+        self.files
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), content.to_vec());
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, DomainError> {
+        self.files
+            .lock()
+            .unwrap()
+            .get(key)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("File {} not found", key)))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), DomainError> {
+        self.files.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
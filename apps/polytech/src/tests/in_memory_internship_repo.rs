@@ -1,30 +1,73 @@
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::internship::Internship;
+use zukmove_core::domain::entities::internship::{
+    Internship, InternshipFilter, InternshipStatusChange,
+};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 use zukmove_core::domain::ports::{DomainError, InternshipRepository};
 
 /// In-memory implementation of InternshipRepository for testing.
+/// Clones share the same data.
+#[derive(Clone)]
 pub struct InMemoryInternshipRepository {
-    store: Mutex<HashMap<Uuid, Internship>>,
+    store: Arc<Mutex<HashMap<Uuid, Internship>>>,
+    history: Arc<Mutex<Vec<InternshipStatusChange>>>,
 }
 
 impl InMemoryInternshipRepository {
     pub fn new() -> Self {
         Self {
-            store: Mutex::new(HashMap::new()),
+            store: Arc::new(Mutex::new(HashMap::new())),
+            history: Arc::new(Mutex::new(Vec::new())),
         }
     }
+
+    /// Removes the internships of a student with their history, as the student's erasure does.
+    pub fn remove_student(&self, student_id: Uuid) -> (Vec<Internship>, u64) {
+        let mut store = self.store.lock().unwrap();
+        let removed: Vec<Internship> = store
+            .values()
+            .filter(|i| i.student_id == student_id)
+            .cloned()
+            .collect();
+        for internship in &removed {
+            store.remove(&internship.id);
+        }
+        let mut history = self.history.lock().unwrap();
+        let before = history.len();
+        history.retain(|c| removed.iter().all(|i| i.id != c.internship_id));
+        (removed, (before - history.len()) as u64)
+    }
 }
 
 #[async_trait]
 impl InternshipRepository for InMemoryInternshipRepository {
     async fn save(&self, internship: &Internship) -> Result<Internship, DomainError> {
         let mut store = self.store.lock().unwrap();
+        // Same rule as the partial unique index: declined and withdrawn applications don't count
+        let live = |i: &Internship| {
+            i.student_id == internship.student_id
+                && i.offer_id == internship.offer_id
+                && !i.status.releases_offer()
+        };
+        if live(internship) && store.values().any(live) {
+            return Err(DomainError::Conflict(format!(
+                "Student {} has already applied to offer {}",
+                internship.student_id, internship.offer_id
+            )));
+        }
         store.insert(internship.id, internship.clone());
+        self.history.lock().unwrap().push(InternshipStatusChange {
+            internship_id: internship.id,
+            from: None,
+            to: internship.status.clone(),
+            message: internship.message.clone(),
+            changed_at: chrono::Utc::now(),
+        });
         Ok(internship.clone())
     }
 
@@ -35,4 +78,89 @@ impl InternshipRepository for InMemoryInternshipRepository {
             .cloned()
             .ok_or_else(|| DomainError::NotFound(format!("Internship with id {} not found", id)))
     }
+
+    async fn find_by_student(
+        &self,
+        student_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        let filter = InternshipFilter {
+            student_id: Some(student_id),
+            ..Default::default()
+        };
+        self.search(&filter, page).await
+    }
+
+    async fn find_by_student_and_offer(
+        &self,
+        student_id: Uuid,
+        offer_id: Uuid,
+    ) -> Result<Option<Internship>, DomainError> {
+        let store = self.store.lock().unwrap();
+        Ok(store
+            .values()
+            .find(|i| {
+                i.student_id == student_id && i.offer_id == offer_id && !i.status.releases_offer()
+            })
+            .cloned())
+    }
+
+    async fn find_by_offer(
+        &self,
+        offer_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        let filter = InternshipFilter {
+            offer_id: Some(offer_id),
+            ..Default::default()
+        };
+        self.search(&filter, page).await
+    }
+
+    async fn search(
+        &self,
+        filter: &InternshipFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        let store = self.store.lock().unwrap();
+        Ok(Page::paginate(
+            store.values().filter(|i| filter.matches(i)).cloned(),
+            page,
+        ))
+    }
+
+    async fn update_status(
+        &self,
+        change: &InternshipStatusChange,
+    ) -> Result<Internship, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        let internship = store.get_mut(&change.internship_id).ok_or_else(|| {
+            DomainError::NotFound(format!(
+                "Internship with id {} not found",
+                change.internship_id
+            ))
+        })?;
+        if Some(&internship.status) != change.from.as_ref() {
+            return Err(DomainError::ValidationError(format!(
+                "Internship {} is no longer in the expected status",
+                change.internship_id
+            )));
+        }
+        internship.status = change.to.clone();
+        internship.message = change.message.clone();
+        self.history.lock().unwrap().push(change.clone());
+        Ok(internship.clone())
+    }
+
+    async fn find_history(
+        &self,
+        internship_id: Uuid,
+    ) -> Result<Vec<InternshipStatusChange>, DomainError> {
+        let history = self.history.lock().unwrap();
+        Ok(history
+            .iter()
+            .filter(|c| c.internship_id == internship_id)
+            .cloned()
+            .collect())
+    }
 }
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::notification::Notification;
+use zukmove_core::domain::pagination::{Page, PageRequest};
+use zukmove_core::domain::ports::{DomainError, NotificationRepository};
+
+/// In-memory implementation of NotificationRepository for testing.
+/// Clones share the same data.
+#[derive(Clone)]
+pub struct InMemoryNotificationRepository {
+    store: Arc<Mutex<HashMap<Uuid, Notification>>>,
+}
+
+impl InMemoryNotificationRepository {
+    pub fn new() -> Self {
+        Self {
+            store: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Removes the notifications of a student, as the student's erasure does.
+    pub fn remove_student(&self, student_id: Uuid) -> u64 {
+        let mut store = self.store.lock().unwrap();
+        let before = store.len();
+        store.retain(|_, n| n.student_id != student_id);
+        (before - store.len()) as u64
+    }
+}
+
+#[async_trait]
+impl NotificationRepository for InMemoryNotificationRepository {
+    async fn save(&self, notification: &Notification) -> Result<Notification, DomainError> {
+        let mut store = self.store.lock().unwrap();
+        store.insert(notification.id, notification.clone());
+        Ok(notification.clone())
+    }
+
+    async fn find_by_student_id(
+        &self,
+        student_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Notification>, DomainError> {
+        let store = self.store.lock().unwrap();
+        Ok(Page::paginate(
+            store
+                .values()
+                .filter(|n| n.student_id == student_id)
+                .cloned(),
+            page,
+        ))
+    }
+
+    async fn mark_as_read(&self, id: Uuid) -> Result<(), DomainError> {
+        let mut store = self.store.lock().unwrap();
+        let notification = store
+            .get_mut(&id)
+            .ok_or_else(|| DomainError::NotFound(format!("Notification {} not found", id)))?;
+        notification.read = true;
+        Ok(())
+    }
+}
@@ -4,18 +4,36 @@ use std::sync::Mutex;
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::student::Student;
+use zukmove_core::domain::entities::student::{ErasedRecords, Student, StudentFilter};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 use zukmove_core::domain::ports::{DomainError, StudentRepository};
 
+use super::in_memory_internship_repo::InMemoryInternshipRepository;
+use super::in_memory_notification_repo::InMemoryNotificationRepository;
+
 /// In-memory implementation of StudentRepository for testing.
 pub struct InMemoryStudentRepository {
     store: Mutex<HashMap<Uuid, Student>>,
+    /// Records erased with a student, like the rows referencing it in Postgres.
+    related: Option<(InMemoryInternshipRepository, InMemoryNotificationRepository)>,
 }
 
 impl InMemoryStudentRepository {
     pub fn new() -> Self {
         Self {
             store: Mutex::new(HashMap::new()),
+            related: None,
+        }
+    }
+
+    /// Erasing a student also removes their records from the given repositories.
+    pub fn cascading_to(
+        internships: InMemoryInternshipRepository,
+        notifications: InMemoryNotificationRepository,
+    ) -> Self {
+        Self {
+            store: Mutex::new(HashMap::new()),
+            related: Some((internships, notifications)),
         }
     }
 }
@@ -36,32 +54,50 @@ impl StudentRepository for InMemoryStudentRepository {
             .ok_or_else(|| DomainError::NotFound(format!("Student with id {} not found", id)))
     }
 
-    async fn find_by_domain(&self, domain: &str) -> Result<Vec<Student>, DomainError> {
+    async fn search(
+        &self,
+        filter: &StudentFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Student>, DomainError> {
         let store = self.store.lock().unwrap();
-        Ok(store
-            .values()
-            .filter(|s| s.domain == domain)
-            .cloned()
-            .collect())
+        Ok(Page::paginate(
+            store.values().filter(|s| filter.matches(s)).cloned(),
+            page,
+        ))
     }
 
     async fn update(&self, student: &Student) -> Result<Student, DomainError> {
         let mut store = self.store.lock().unwrap();
-        if !store.contains_key(&student.id) {
-            return Err(DomainError::NotFound(format!(
-                "Student with id {} not found",
-                student.id
+        let current = store.get(&student.id).ok_or_else(|| {
+            DomainError::NotFound(format!("Student with id {} not found", student.id))
+        })?;
+        if current.version != student.version {
+            return Err(DomainError::PreconditionFailed(format!(
+                "Student with id {} was modified since version {}",
+                student.id, student.version
             )));
         }
-        store.insert(student.id, student.clone());
-        Ok(student.clone())
+        let updated = Student {
+            version: student.version + 1,
+            ..student.clone()
+        };
+        store.insert(student.id, updated.clone());
+        Ok(updated)
     }
 
-    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+    async fn erase(&self, id: Uuid) -> Result<ErasedRecords, DomainError> {
         let mut store = self.store.lock().unwrap();
         store
             .remove(&id)
             .ok_or_else(|| DomainError::NotFound(format!("Student with id {} not found", id)))?;
-        Ok(())
+        let Some((ref internships, ref notifications)) = self.related else {
+            return Ok(ErasedRecords::default());
+        };
+        let (internships, status_changes) = internships.remove_student(id);
+        Ok(ErasedRecords {
+            internships,
+            status_changes,
+            notifications: notifications.remove_student(id),
+        })
     }
 }
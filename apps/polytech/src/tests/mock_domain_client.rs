@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::domain::{Domain, DomainTaxonomy};
+use zukmove_core::domain::ports::{DomainClient, DomainError};
+
+/// Mock DomainClient returning a fixed catalog: IT (with aliases and a Data Science
+/// sub-domain), Life Science, Business and Santé.
+pub struct MockDomainClient {
+    domains: Vec<Domain>,
+}
+
+impl MockDomainClient {
+    pub fn new() -> Self {
+        let it = Domain {
+            id: Uuid::new_v4(),
+            name: "IT".to_string(),
+            parent_id: None,
+            aliases: vec!["Informatique".to_string(), "Computer Science".to_string()],
+        };
+        let data_science = Domain {
+            id: Uuid::new_v4(),
+            name: "Data Science".to_string(),
+            parent_id: Some(it.id),
+            aliases: vec![],
+        };
+        let others = ["Life Science", "Business", "Santé"].map(|name| Domain {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            parent_id: None,
+            aliases: vec![],
+        });
+        Self {
+            domains: [it, data_science].into_iter().chain(others).collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainClient for MockDomainClient {
+    async fn get_taxonomy(&self) -> Result<DomainTaxonomy, DomainError> {
+        Ok(DomainTaxonomy::new(self.domains.clone()))
+    }
+}
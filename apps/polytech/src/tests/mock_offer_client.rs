@@ -5,18 +5,23 @@ use async_trait::async_trait;
 use chrono::NaiveDate;
 use uuid::Uuid;
 
-use zukmove_core::domain::entities::offer::Offer;
+use zukmove_core::domain::entities::company::Company;
+use zukmove_core::domain::entities::money::{Money, SalaryPeriod};
+use zukmove_core::domain::entities::offer::{Offer, OfferFilter, RemotePolicy};
+use zukmove_core::domain::pagination::{Page, PageRequest};
 use zukmove_core::domain::ports::{DomainError, OfferClient};
 
 /// Mock OfferClient that simulates responses from Erasmumu.
 pub struct MockOfferClient {
     offers: Mutex<HashMap<Uuid, Offer>>,
+    companies: Mutex<HashMap<Uuid, Company>>,
 }
 
 impl MockOfferClient {
     pub fn new() -> Self {
         Self {
             offers: Mutex::new(HashMap::new()),
+            companies: Mutex::new(HashMap::new()),
         }
     }
 
@@ -25,6 +30,12 @@ impl MockOfferClient {
         let mut store = self.offers.lock().unwrap();
         store.insert(offer.id, offer);
     }
+
+    /// Pre-load a company of the Erasmumu registry.
+    pub fn add_company(&self, company: Company) {
+        let mut store = self.companies.lock().unwrap();
+        store.insert(company.id, company);
+    }
 }
 
 #[async_trait]
@@ -38,20 +49,56 @@ impl OfferClient for MockOfferClient {
     }
     async fn search_offers(
         &self,
-        domain: Option<String>,
-        city: Option<String>,
-    ) -> Result<Vec<Offer>, DomainError> {
+        filter: &OfferFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Offer>, DomainError> {
         let store = self.offers.lock().unwrap();
-        let mut results: Vec<Offer> = store.values().cloned().collect();
+        Ok(Page::paginate(
+            store.values().filter(|o| filter.matches(o)).cloned(),
+            page,
+        ))
+    }
+
+    async fn reserve_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
+        let mut store = self.offers.lock().unwrap();
+        let offer = store
+            .get_mut(&id)
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
+        if offer.remaining_seats() == 0 {
+            return Err(DomainError::Conflict(format!(
+                "Offer with id {} has no seats left",
+                id
+            )));
+        }
+        offer.reserved_seats += 1;
+        offer.available = offer.available && offer.remaining_seats() > 0;
+        Ok(offer.clone())
+    }
 
-        if let Some(d) = domain {
-            results.retain(|o| o.domain == d);
+    async fn release_seat(&self, id: Uuid) -> Result<Offer, DomainError> {
+        let mut store = self.offers.lock().unwrap();
+        let offer = store
+            .get_mut(&id)
+            .ok_or_else(|| DomainError::NotFound(format!("Offer with id {} not found", id)))?;
+        if offer.reserved_seats == 0 {
+            return Err(DomainError::Conflict(format!(
+                "Offer with id {} has no reserved seat",
+                id
+            )));
         }
-        if let Some(c) = city {
-            results.retain(|o| o.city == c);
+        if offer.remaining_seats() == 0 {
+            offer.available = true;
         }
+        offer.reserved_seats -= 1;
+        Ok(offer.clone())
+    }
 
-        Ok(results)
+    async fn get_company(&self, id: Uuid) -> Result<Company, DomainError> {
+        let store = self.companies.lock().unwrap();
+        store
+            .get(&id)
+            .cloned()
+            .ok_or_else(|| DomainError::NotFound(format!("Company with id {} not found", id)))
     }
 }
 
@@ -63,9 +110,20 @@ pub fn make_test_offer(id: Uuid, domain: &str) -> Offer {
         link: "http://example.com".to_string(),
         city: "Paris".to_string(),
         domain: domain.to_string(),
-        salary: 1200.0,
-        start_date: NaiveDate::from_ymd_opt(2026, 6, 1).unwrap(),
-        end_date: NaiveDate::from_ymd_opt(2026, 12, 1).unwrap(),
+        salary: Money::new(120_000, "EUR", SalaryPeriod::Monthly),
+        base_salary: Some(Money::new(120_000, "EUR", SalaryPeriod::Monthly)),
+        start_date: NaiveDate::from_ymd_opt(2099, 6, 1).unwrap(),
+        end_date: NaiveDate::from_ymd_opt(2099, 12, 1).unwrap(),
         available: true,
+        seats: 1,
+        reserved_seats: 0,
+        deleted_at: None,
+        application_deadline: None,
+        company_id: None,
+        description: None,
+        skills: vec![],
+        languages: vec![],
+        remote_policy: RemotePolicy::OnSite,
+        version: 1,
     }
 }
@@ -0,0 +1,35 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use zukmove_core::domain::entities::subscriber::Subscriber;
+use zukmove_core::domain::ports::{DomainError, SubscriberClient};
+
+/// Mock SubscriberClient that simulates the subscribers held by La Poste.
+pub struct MockSubscriberClient {
+    subscribers: Mutex<HashMap<Uuid, Subscriber>>,
+}
+
+impl MockSubscriberClient {
+    pub fn new() -> Self {
+        Self {
+            subscribers: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Pre-load the subscriber of a student.
+    pub fn add_subscriber(&self, student_id: Uuid, subscriber: Subscriber) {
+        let mut store = self.subscribers.lock().unwrap();
+        store.insert(student_id, subscriber);
+    }
+}
+
+#[async_trait]
+impl SubscriberClient for MockSubscriberClient {
+    async fn get_subscriber(&self, student_id: Uuid) -> Result<Option<Subscriber>, DomainError> {
+        let store = self.subscribers.lock().unwrap();
+        Ok(store.get(&student_id).cloned())
+    }
+}
@@ -1,7 +1,11 @@
+mod in_memory_file_storage;
 mod in_memory_internship_repo;
+mod in_memory_notification_repo;
 mod in_memory_student_repo;
+mod mock_domain_client;
 mod mock_intelligence_client;
 mod mock_offer_client;
+mod mock_subscriber_client;
 
 use actix_web::{App, test, web};
 use serde_json::json;
@@ -9,23 +13,53 @@ use uuid::Uuid;
 
 use crate::AppState;
 use crate::routes;
+use in_memory_file_storage::InMemoryFileStorage;
 use in_memory_internship_repo::InMemoryInternshipRepository;
+use in_memory_notification_repo::InMemoryNotificationRepository;
 use in_memory_student_repo::InMemoryStudentRepository;
+use mock_domain_client::MockDomainClient;
 use mock_offer_client::{MockOfferClient, make_test_offer};
+use mock_subscriber_client::MockSubscriberClient;
 use zukmove_core::app::internship_service::InternshipService;
-use zukmove_core::domain::entities::internship::Internship;
-use zukmove_core::domain::entities::student::Student;
-use zukmove_core::domain::ports::StudentRepository;
+use zukmove_core::domain::concurrency::etag;
+use zukmove_core::domain::eligibility::EligibilityPolicy;
+use zukmove_core::domain::entities::company::Company;
+use zukmove_core::domain::entities::gateway::AggregatedOffer;
+use zukmove_core::domain::entities::internship::{
+    Internship, InternshipStatus, InternshipStatusChange,
+};
+use zukmove_core::domain::entities::notification::Notification;
+use zukmove_core::domain::entities::offer::Offer;
+use zukmove_core::domain::entities::student::{ErasureReport, Student, StudentExport};
+use zukmove_core::domain::entities::subscriber::Subscriber;
+use zukmove_core::domain::pagination::Page;
+use zukmove_core::domain::ports::{
+    InternshipRepository, NotificationRepository, StudentRepository,
+};
+use zukmove_core::domain::validation::ValidationFailure;
 
 fn test_app_state() -> web::Data<AppState> {
+    let internship_repo = InMemoryInternshipRepository::new();
+    let notification_repo = InMemoryNotificationRepository::new();
     web::Data::new(AppState {
-        student_repo: Box::new(InMemoryStudentRepository::new()),
+        student_repo: Box::new(InMemoryStudentRepository::cascading_to(
+            internship_repo.clone(),
+            notification_repo.clone(),
+        )),
+        domain_client: Box::new(MockDomainClient::new()),
         internship_service: InternshipService::new(
             Box::new(InMemoryStudentRepository::new()),
-            Box::new(InMemoryInternshipRepository::new()),
+            Box::new(internship_repo),
             Box::new(MockOfferClient::new()),
+            Box::new(MockDomainClient::new()),
             std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
         ),
+        notification_repo: Box::new(notification_repo),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
     })
 }
 
@@ -53,14 +87,42 @@ fn test_app(
             "/student/{id}",
             web::delete().to(routes::student::delete_student),
         )
+        .service(
+            web::resource("/student/{id}/cv")
+                .app_data(web::PayloadConfig::new(routes::student::MAX_CV_SIZE))
+                .route(web::put().to(routes::student::upload_cv))
+                .route(web::get().to(routes::student::download_cv))
+                .route(web::delete().to(routes::student::delete_cv)),
+        )
+        .route(
+            "/student/{id}/export",
+            web::get().to(routes::student::export_student),
+        )
+        .route(
+            "/student/{id}/internships",
+            web::get().to(routes::internship::list_student_internships),
+        )
         .route(
             "/internship",
             web::post().to(routes::internship::create_internship),
         )
+        .route(
+            "/internship",
+            web::get().to(routes::internship::list_internships),
+        )
         .route(
             "/internship/{id}",
             web::get().to(routes::internship::get_internship),
         )
+        .route(
+            "/internship/{id}/status",
+            web::put().to(routes::internship::update_internship_status),
+        )
+        .route(
+            "/internship/{id}/history",
+            web::get().to(routes::internship::get_internship_history),
+        )
+        .route("/offer", web::get().to(routes::offer::get_offers))
 }
 
 // ─── POST /student ───
@@ -90,6 +152,43 @@ async fn test_create_student() {
     assert_eq!(body.domain, "IT");
 }
 
+#[actix_web::test]
+async fn test_create_student_resolves_domain_against_catalog() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/student")
+        .set_json(json!({ "firstname": "Alice", "name": "Dupont", "domain": "informatique" }))
+        .to_request();
+    let body: Student = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.domain, "IT");
+
+    let req = test::TestRequest::post()
+        .uri("/student")
+        .set_json(json!({ "firstname": "Bob", "name": "Martin", "domain": "Astrologie" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_create_student_lists_invalid_fields() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/student")
+        .set_json(json!({ "firstname": " ", "name": "", "domain": "IT" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+
+    let body: ValidationFailure = test::read_body_json(resp).await;
+    let fields: Vec<&str> = body.fields.iter().map(|f| f.field.as_str()).collect();
+    assert_eq!(fields, vec!["firstname", "name"]);
+}
+
 // ─── GET /student/{id} ───
 
 #[actix_web::test]
@@ -155,19 +254,132 @@ async fn test_list_students_by_domain() {
 
     assert_eq!(resp.status(), 200);
 
-    let body: Vec<Student> = test::read_body_json(resp).await;
-    assert_eq!(body.len(), 2);
+    let body: Page<Student> = test::read_body_json(resp).await;
+    assert_eq!(body.items.len(), 2);
+}
+
+#[actix_web::test]
+async fn test_list_students_by_domain_ignores_case_and_accents() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    for domain in ["Santé", "sante", "SANTE "] {
+        let payload = json!({ "firstname": "Alice", "name": "Test", "domain": domain });
+        let req = test::TestRequest::post()
+            .uri("/student")
+            .set_json(&payload)
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/student?domain=sant%C3%A9")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 200);
+
+    let body: Page<Student> = test::read_body_json(resp).await;
+    assert_eq!(body.items.len(), 3);
+}
+
+#[actix_web::test]
+async fn test_list_students_pages_by_firstname() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    for name in ["Claire", "Alice", "Bob"] {
+        let payload = json!({ "firstname": name, "name": "Test", "domain": "IT" });
+        let req = test::TestRequest::post()
+            .uri("/student")
+            .set_json(&payload)
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/student?domain=IT&limit=2&sort=firstname")
+        .to_request();
+    let first: Page<Student> = test::call_and_read_body_json(&app, req).await;
+    let names: Vec<&str> = first.items.iter().map(|s| s.firstname.as_str()).collect();
+    assert_eq!(names, vec!["Alice", "Bob"]);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/student?domain=IT&limit=2&sort=firstname&cursor={}",
+            first.next_cursor.expect("a second page")
+        ))
+        .to_request();
+    let second: Page<Student> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(second.items.len(), 1);
+    assert_eq!(second.items[0].firstname, "Claire");
+    assert!(second.next_cursor.is_none());
 }
 
 #[actix_web::test]
-async fn test_list_students_without_domain_returns_400() {
+async fn test_list_students_without_filter_returns_whole_cohort() {
     let state = test_app_state();
     let app = test::init_service(test_app(state)).await;
 
+    for (name, domain) in [("Alice", "IT"), ("Claire", "life science")] {
+        let payload = json!({ "firstname": name, "name": "Test", "domain": domain });
+        let req = test::TestRequest::post()
+            .uri("/student")
+            .set_json(&payload)
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
     let req = test::TestRequest::get().uri("/student").to_request();
     let resp = test::call_service(&app, req).await;
 
-    assert_eq!(resp.status(), 400);
+    assert_eq!(resp.status(), 200);
+
+    let body: Page<Student> = test::read_body_json(resp).await;
+    assert_eq!(body.items.len(), 2);
+}
+
+#[actix_web::test]
+async fn test_search_students_by_name_prefix_and_study_year() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    for (firstname, name, year) in [
+        ("Éloïse", "Martin", 4),
+        ("Marc", "Dupont", 3),
+        ("Anne", "Lemarié", 4),
+    ] {
+        let payload = json!({
+            "firstname": firstname,
+            "name": name,
+            "domain": "IT",
+            "study_year": year
+        });
+        let req = test::TestRequest::post()
+            .uri("/student")
+            .set_json(&payload)
+            .to_request();
+        test::call_service(&app, req).await;
+    }
+
+    let req = test::TestRequest::get()
+        .uri("/student?name=MAR&sort=name")
+        .to_request();
+    let body: Page<Student> = test::call_and_read_body_json(&app, req).await;
+    let names: Vec<&str> = body.items.iter().map(|s| s.firstname.as_str()).collect();
+    assert_eq!(names, vec!["Marc", "Éloïse"]);
+
+    let req = test::TestRequest::get()
+        .uri("/student?name=elo&study_year=4")
+        .to_request();
+    let body: Page<Student> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 1);
+
+    let req = test::TestRequest::get()
+        .uri("/student?study_year=4&domain=IT")
+        .to_request();
+    let body: Page<Student> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 2);
 }
 
 // ─── PUT /student/{id} ───
@@ -200,6 +412,177 @@ async fn test_update_student() {
     assert_eq!(body.name, "Dupont"); // unchanged
 }
 
+#[actix_web::test]
+async fn test_stale_student_update_is_refused_with_412() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({ "firstname": "Alice", "name": "Dupont", "domain": "IT" });
+    let req = test::TestRequest::post()
+        .uri("/student")
+        .set_json(&payload)
+        .to_request();
+    let created: Student = test::call_and_read_body_json(&app, req).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    let etag = resp
+        .headers()
+        .get("ETag")
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/student/{}", created.id))
+        .insert_header(("If-Match", etag.as_str()))
+        .set_json(json!({ "firstname": "Alicia" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_ne!(resp.headers().get("ETag").unwrap().to_str().unwrap(), etag);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/student/{}", created.id))
+        .insert_header(("If-Match", etag.as_str()))
+        .set_json(json!({ "name": "Martin" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 412);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}", created.id))
+        .to_request();
+    let current: Student = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(current.firstname, "Alicia");
+    assert_eq!(current.name, "Dupont");
+}
+
+#[actix_web::test]
+async fn test_student_profile_and_cv() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({
+        "firstname": "Alice",
+        "name": "Dupont",
+        "domain": "IT",
+        "email": "alice.dupont@etu.example.fr",
+        "phone": "+33 6 12 34 56 78",
+        "study_year": 4,
+        "languages": [{ "language": "English", "level": "C1" }]
+    });
+    let req = test::TestRequest::post()
+        .uri("/student")
+        .set_json(&payload)
+        .to_request();
+    let created: Student = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(
+        created.email.as_deref(),
+        Some("alice.dupont@etu.example.fr")
+    );
+    assert_eq!(created.study_year, Some(4));
+    assert!(created.cv.is_none());
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/student/{}", created.id))
+        .set_json(json!({ "phone": null, "study_year": 9, "email": "alice" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 422);
+    let failure: ValidationFailure = test::read_body_json(resp).await;
+    let fields: Vec<&str> = failure.fields.iter().map(|f| f.field.as_str()).collect();
+    assert_eq!(fields, vec!["email", "study_year"]);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/student/{}", created.id))
+        .set_json(json!({ "phone": null }))
+        .to_request();
+    let updated: Student = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(updated.phone, None);
+    assert_eq!(updated.languages.len(), 1);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/student/{}/cv", created.id))
+        .insert_header(("Content-Type", "image/png"))
+        .set_payload("not a CV")
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 415);
+
+    let req = test::TestRequest::put()
+        .uri(&format!(
+            "/student/{}/cv?filename=Alice%20Dupont.pdf",
+            created.id
+        ))
+        .insert_header(("Content-Type", "application/pdf"))
+        .set_payload("%PDF-1.7 ...")
+        .to_request();
+    let with_cv: Student = test::call_and_read_body_json(&app, req).await;
+    let cv = with_cv.cv.unwrap();
+    assert_eq!(cv.filename, "Alice Dupont.pdf");
+    assert_eq!(cv.size, 12);
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}/cv", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert_eq!(
+        resp.headers().get("Content-Type").unwrap(),
+        "application/pdf"
+    );
+    assert!(
+        resp.headers()
+            .get("Content-Disposition")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("attachment")
+    );
+    assert_eq!(test::read_body(resp).await, "%PDF-1.7 ...");
+
+    // A stale upload leaves the stored CV untouched
+    let req = test::TestRequest::put()
+        .uri(&format!("/student/{}/cv", created.id))
+        .insert_header(("Content-Type", "application/pdf"))
+        .insert_header(("If-Match", etag(created.version)))
+        .set_payload("%PDF-1.7 stale")
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 412);
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/student/{}/cv?filename=cv-2.pdf", created.id))
+        .insert_header(("Content-Type", "application/pdf"))
+        .insert_header(("If-Match", etag(with_cv.version)))
+        .set_payload("%PDF-1.7 v2")
+        .to_request();
+    let replaced: Student = test::call_and_read_body_json(&app, req).await;
+    assert_ne!(replaced.cv.as_ref().unwrap().key, cv.key);
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}/cv", created.id))
+        .to_request();
+    assert_eq!(test::call_and_read_body(&app, req).await, "%PDF-1.7 v2");
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/student/{}/cv", created.id))
+        .insert_header(("If-Match", etag(with_cv.version)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 412);
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/student/{}/cv", created.id))
+        .insert_header(("If-Match", etag(replaced.version)))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 204);
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}/cv", created.id))
+        .to_request();
+    assert_eq!(test::call_service(&app, req).await.status(), 404);
+}
+
 // ─── DELETE /student/{id} ───
 
 #[actix_web::test]
@@ -221,7 +604,10 @@ async fn test_delete_student() {
         .to_request();
     let resp = test::call_service(&app, req).await;
 
-    assert_eq!(resp.status(), 204);
+    assert_eq!(resp.status(), 200);
+    let report: ErasureReport = test::read_body_json(resp).await;
+    assert_eq!(report.student_id, created.id);
+    assert!(report.verified);
 
     // Verify gone
     let req = test::TestRequest::get()
@@ -232,6 +618,98 @@ async fn test_delete_student() {
     assert_eq!(resp.status(), 404);
 }
 
+#[actix_web::test]
+async fn test_erasing_a_student_removes_their_records_and_releases_seats() {
+    let student_repo_for_service = InMemoryStudentRepository::new();
+    let internship_repo = InMemoryInternshipRepository::new();
+    let notification_repo = InMemoryNotificationRepository::new();
+    let student_repo =
+        InMemoryStudentRepository::cascading_to(internship_repo.clone(), notification_repo.clone());
+    let offer_client = MockOfferClient::new();
+
+    let student = Student {
+        id: Uuid::new_v4(),
+        firstname: "Alice".to_string(),
+        name: "Dupont".to_string(),
+        domain: "IT".to_string(),
+        email: Some("alice@example.com".to_string()),
+        phone: None,
+        study_year: None,
+        languages: vec![],
+        cv: None,
+        version: 1,
+    };
+    student_repo.save(&student).await.unwrap();
+
+    // Only the accepted application holds a seat
+    let (pending_offer, accepted_offer) = (Uuid::new_v4(), Uuid::new_v4());
+    for (offer_id, status) in [
+        (pending_offer, InternshipStatus::Submitted),
+        (accepted_offer, InternshipStatus::Accepted),
+    ] {
+        offer_client.add_offer(Offer {
+            reserved_seats: 1,
+            ..make_test_offer(offer_id, "IT")
+        });
+        internship_repo
+            .save(&Internship {
+                id: Uuid::new_v4(),
+                student_id: student.id,
+                offer_id,
+                status,
+                message: "Candidature soumise".to_string(),
+                reasons: vec![],
+            })
+            .await
+            .unwrap();
+    }
+    notification_repo
+        .save(&Notification {
+            id: Uuid::new_v4(),
+            student_id: student.id,
+            type_: "new_offer".to_string(),
+            offer_id: pending_offer,
+            message: "Nouvelle offre".to_string(),
+            read: false,
+            stale: false,
+        })
+        .await
+        .unwrap();
+
+    let state = web::Data::new(AppState {
+        student_repo: Box::new(student_repo),
+        domain_client: Box::new(MockDomainClient::new()),
+        internship_service: InternshipService::new(
+            Box::new(student_repo_for_service),
+            Box::new(internship_repo),
+            Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
+            std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
+        ),
+        notification_repo: Box::new(notification_repo),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
+    });
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::delete()
+        .uri(&format!("/student/{}", student.id))
+        .to_request();
+    let report: ErasureReport = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(report.internships, 2);
+    assert_eq!(report.status_changes, 2);
+    assert_eq!(report.notifications, 1);
+    assert_eq!(report.released_seats, vec![accepted_offer]);
+    assert!(!report.cv_deleted);
+    // No broker in tests: La Poste is not told
+    assert!(!report.deletion_published);
+    assert!(report.verified);
+}
+
 #[actix_web::test]
 async fn test_delete_student_not_found() {
     let state = test_app_state();
@@ -245,10 +723,116 @@ async fn test_delete_student_not_found() {
     assert_eq!(resp.status(), 404);
 }
 
-// ─── POST /internship (domain match → approved) ───
+// ─── GET /student/{id}/export ───
 
 #[actix_web::test]
-async fn test_internship_approved_when_domains_match() {
+async fn test_export_gathers_every_record_of_a_student() {
+    let internship_repo = InMemoryInternshipRepository::new();
+    let notification_repo = InMemoryNotificationRepository::new();
+    let student_repo = InMemoryStudentRepository::new();
+    let subscriber_client = MockSubscriberClient::new();
+
+    let student = Student {
+        id: Uuid::new_v4(),
+        firstname: "Alice".to_string(),
+        name: "Dupont".to_string(),
+        domain: "IT".to_string(),
+        email: Some("alice@example.com".to_string()),
+        phone: None,
+        study_year: Some(4),
+        languages: vec![],
+        cv: None,
+        version: 1,
+    };
+    student_repo.save(&student).await.unwrap();
+    let offer_id = Uuid::new_v4();
+    internship_repo
+        .save(&Internship {
+            id: Uuid::new_v4(),
+            student_id: student.id,
+            offer_id,
+            status: InternshipStatus::Submitted,
+            message: "Candidature soumise".to_string(),
+            reasons: vec![],
+        })
+        .await
+        .unwrap();
+    notification_repo
+        .save(&Notification {
+            id: Uuid::new_v4(),
+            student_id: student.id,
+            type_: "new_offer".to_string(),
+            offer_id,
+            message: "Nouvelle offre".to_string(),
+            read: true,
+            stale: false,
+        })
+        .await
+        .unwrap();
+    subscriber_client.add_subscriber(
+        student.id,
+        Subscriber {
+            student_id: student.id.to_string(),
+            domain: "IT".to_string(),
+            channel: "discord".to_string(),
+            contact: "alice#1234".to_string(),
+            enabled: false,
+        },
+    );
+
+    let state = web::Data::new(AppState {
+        student_repo: Box::new(student_repo),
+        domain_client: Box::new(MockDomainClient::new()),
+        internship_service: InternshipService::new(
+            Box::new(InMemoryStudentRepository::new()),
+            Box::new(internship_repo),
+            Box::new(MockOfferClient::new()),
+            Box::new(MockDomainClient::new()),
+            std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
+        ),
+        notification_repo: Box::new(notification_repo),
+        subscriber_client: Box::new(subscriber_client),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
+    });
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}/export", student.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+    assert!(
+        resp.headers()
+            .get("content-disposition")
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|v| v.contains("attachment"))
+    );
+
+    let export: StudentExport = test::read_body_json(resp).await;
+    assert_eq!(export.student.email.as_deref(), Some("alice@example.com"));
+    assert_eq!(export.internships.len(), 1);
+    assert_eq!(export.internship_history.len(), 1);
+    assert_eq!(export.notifications.len(), 1);
+    assert_eq!(
+        export.subscriber.map(|s| s.channel),
+        Some("discord".to_string())
+    );
+
+    // Unknown student
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}/export", Uuid::new_v4()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 404);
+}
+
+// ─── POST /internship (domain match → submitted) ───
+
+#[actix_web::test]
+async fn test_internship_submitted_when_domains_match() {
     // Build state with shared repos so student + mock offer share the same data
     let student_repo = InMemoryStudentRepository::new();
     let student_repo_for_service = InMemoryStudentRepository::new();
@@ -261,6 +845,12 @@ async fn test_internship_approved_when_domains_match() {
         firstname: "Alice".to_string(),
         name: "Dupont".to_string(),
         domain: "IT".to_string(),
+        email: None,
+        phone: None,
+        study_year: None,
+        languages: vec![],
+        cv: None,
+        version: 1,
     };
     student_repo.save(&student).await.unwrap();
     student_repo_for_service.save(&student).await.unwrap();
@@ -271,12 +861,20 @@ async fn test_internship_approved_when_domains_match() {
 
     let state = web::Data::new(AppState {
         student_repo: Box::new(student_repo),
+        domain_client: Box::new(MockDomainClient::new()),
         internship_service: InternshipService::new(
             Box::new(student_repo_for_service),
             Box::new(internship_repo),
             Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
             std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
         ),
+        notification_repo: Box::new(InMemoryNotificationRepository::new()),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
     });
 
     let app = test::init_service(test_app(state)).await;
@@ -295,16 +893,13 @@ async fn test_internship_approved_when_domains_match() {
     assert_eq!(resp.status(), 201);
 
     let body: Internship = test::read_body_json(resp).await;
-    assert_eq!(
-        body.status,
-        zukmove_core::domain::entities::internship::InternshipStatus::Approved
-    );
+    assert_eq!(body.status, InternshipStatus::Submitted);
 }
 
-// ─── POST /internship (domain mismatch → rejected) ───
+// ─── POST /internship (domain mismatch → declined) ───
 
 #[actix_web::test]
-async fn test_internship_rejected_when_domains_dont_match() {
+async fn test_internship_declined_when_domains_dont_match() {
     let student_repo = InMemoryStudentRepository::new();
     let student_repo_for_service = InMemoryStudentRepository::new();
     let internship_repo = InMemoryInternshipRepository::new();
@@ -315,6 +910,12 @@ async fn test_internship_rejected_when_domains_dont_match() {
         firstname: "Bob".to_string(),
         name: "Martin".to_string(),
         domain: "IT".to_string(),
+        email: None,
+        phone: None,
+        study_year: None,
+        languages: vec![],
+        cv: None,
+        version: 1,
     };
     student_repo.save(&student).await.unwrap();
     student_repo_for_service.save(&student).await.unwrap();
@@ -325,12 +926,20 @@ async fn test_internship_rejected_when_domains_dont_match() {
 
     let state = web::Data::new(AppState {
         student_repo: Box::new(student_repo),
+        domain_client: Box::new(MockDomainClient::new()),
         internship_service: InternshipService::new(
             Box::new(student_repo_for_service),
             Box::new(internship_repo),
             Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
             std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
         ),
+        notification_repo: Box::new(InMemoryNotificationRepository::new()),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
     });
 
     let app = test::init_service(test_app(state)).await;
@@ -349,11 +958,10 @@ async fn test_internship_rejected_when_domains_dont_match() {
     assert_eq!(resp.status(), 201);
 
     let body: Internship = test::read_body_json(resp).await;
-    assert_eq!(
-        body.status,
-        zukmove_core::domain::entities::internship::InternshipStatus::Rejected
-    );
+    assert_eq!(body.status, InternshipStatus::Declined);
     assert!(body.message.contains("ne correspond pas"));
+    assert_eq!(body.reasons.len(), 1);
+    assert_eq!(body.reasons[0].rule, "domain_match");
 }
 
 // ─── GET /internship/{id} ───
@@ -381,12 +989,20 @@ async fn test_internship_with_unknown_student_returns_404() {
 
     let state = web::Data::new(AppState {
         student_repo: Box::new(InMemoryStudentRepository::new()),
+        domain_client: Box::new(MockDomainClient::new()),
         internship_service: InternshipService::new(
             Box::new(InMemoryStudentRepository::new()),
             Box::new(InMemoryInternshipRepository::new()),
             Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
             std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
         ),
+        notification_repo: Box::new(InMemoryNotificationRepository::new()),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
     });
 
     let app = test::init_service(test_app(state)).await;
@@ -425,3 +1041,460 @@ async fn test_internship_with_unknown_offer_returns_404() {
 
     assert_eq!(resp.status(), 404);
 }
+
+// ─── PUT /internship/{id}/status ───
+
+/// Builds a state with one IT student and one IT offer known to the service.
+async fn state_with_student_and_offer() -> (web::Data<AppState>, Uuid, Uuid) {
+    let student_repo_for_service = InMemoryStudentRepository::new();
+    let offer_client = MockOfferClient::new();
+
+    let student = Student {
+        id: Uuid::new_v4(),
+        firstname: "Alice".to_string(),
+        name: "Dupont".to_string(),
+        domain: "IT".to_string(),
+        email: None,
+        phone: None,
+        study_year: None,
+        languages: vec![],
+        cv: None,
+        version: 1,
+    };
+    student_repo_for_service.save(&student).await.unwrap();
+
+    let offer_id = Uuid::new_v4();
+    offer_client.add_offer(make_test_offer(offer_id, "IT"));
+
+    let state = web::Data::new(AppState {
+        student_repo: Box::new(InMemoryStudentRepository::new()),
+        domain_client: Box::new(MockDomainClient::new()),
+        internship_service: InternshipService::new(
+            Box::new(student_repo_for_service),
+            Box::new(InMemoryInternshipRepository::new()),
+            Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
+            std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
+        ),
+        notification_repo: Box::new(InMemoryNotificationRepository::new()),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
+    });
+
+    (state, student.id, offer_id)
+}
+
+#[actix_web::test]
+async fn test_internship_moves_through_lifecycle() {
+    let (state, student_id, offer_id) = state_with_student_and_offer().await;
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(json!({ "student_id": student_id, "offer_id": offer_id }))
+        .to_request();
+    let created: Internship = test::call_and_read_body_json(&app, req).await;
+
+    for status in ["UnderReview", "Interview", "Accepted"] {
+        let req = test::TestRequest::put()
+            .uri(&format!("/internship/{}/status", created.id))
+            .set_json(json!({ "status": status }))
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+
+        let body: Internship = test::read_body_json(resp).await;
+        assert_eq!(body.status.to_string(), status);
+    }
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/internship/{}/history", created.id))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), 200);
+
+    let history: Vec<InternshipStatusChange> = test::read_body_json(resp).await;
+    assert_eq!(history.len(), 4);
+    assert_eq!(history[0].from, None);
+    assert_eq!(history[0].to, InternshipStatus::Submitted);
+    assert_eq!(history[3].from, Some(InternshipStatus::Interview));
+    assert_eq!(history[3].to, InternshipStatus::Accepted);
+}
+
+#[actix_web::test]
+async fn test_invalid_status_transition_returns_400() {
+    let (state, student_id, offer_id) = state_with_student_and_offer().await;
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(json!({ "student_id": student_id, "offer_id": offer_id }))
+        .to_request();
+    let created: Internship = test::call_and_read_body_json(&app, req).await;
+
+    // Submitted → Accepted skips the review
+    let req = test::TestRequest::put()
+        .uri(&format!("/internship/{}/status", created.id))
+        .set_json(json!({ "status": "Accepted" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 400);
+}
+
+#[actix_web::test]
+async fn test_update_status_of_unknown_internship_returns_404() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::put()
+        .uri(&format!("/internship/{}/status", Uuid::new_v4()))
+        .set_json(json!({ "status": "UnderReview" }))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+}
+
+// ─── POST /internship (duplicates) ───
+
+#[actix_web::test]
+async fn test_duplicate_application_returns_existing_one() {
+    let (state, student_id, offer_id) = state_with_student_and_offer().await;
+    let app = test::init_service(test_app(state)).await;
+
+    let payload = json!({ "student_id": student_id, "offer_id": offer_id });
+
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(&payload)
+        .to_request();
+    let first: Internship = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(first.status, InternshipStatus::Submitted);
+
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(&payload)
+        .to_request();
+    let second: Internship = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(second.id, first.id);
+    assert_eq!(second.status, InternshipStatus::Submitted);
+
+    // No second history entry was recorded
+    let req = test::TestRequest::get()
+        .uri(&format!("/internship/{}/history", first.id))
+        .to_request();
+    let history: Vec<InternshipStatusChange> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(history.len(), 1);
+}
+
+// ─── Seat reservation on acceptance ───
+
+#[actix_web::test]
+async fn test_only_accepted_applications_take_a_seat() {
+    let student_repo_for_service = InMemoryStudentRepository::new();
+    let offer_client = MockOfferClient::new();
+
+    let mut students = Vec::new();
+    for firstname in ["Alice", "Bob", "Carol"] {
+        let student = Student {
+            id: Uuid::new_v4(),
+            firstname: firstname.to_string(),
+            name: "Test".to_string(),
+            domain: "IT".to_string(),
+            email: None,
+            phone: None,
+            study_year: None,
+            languages: vec![],
+            cv: None,
+            version: 1,
+        };
+        student_repo_for_service.save(&student).await.unwrap();
+        students.push(student.id);
+    }
+
+    // Single-seat offer
+    let offer_id = Uuid::new_v4();
+    offer_client.add_offer(make_test_offer(offer_id, "IT"));
+
+    let state = web::Data::new(AppState {
+        student_repo: Box::new(InMemoryStudentRepository::new()),
+        domain_client: Box::new(MockDomainClient::new()),
+        internship_service: InternshipService::new(
+            Box::new(student_repo_for_service),
+            Box::new(InMemoryInternshipRepository::new()),
+            Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
+            std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
+        ),
+        notification_repo: Box::new(InMemoryNotificationRepository::new()),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
+    });
+    let app = test::init_service(test_app(state)).await;
+
+    // Pending applications do not use up the seat
+    let mut applications = Vec::new();
+    for student_id in &students[..2] {
+        let req = test::TestRequest::post()
+            .uri("/internship")
+            .set_json(json!({ "student_id": student_id, "offer_id": offer_id }))
+            .to_request();
+        let internship: Internship = test::call_and_read_body_json(&app, req).await;
+        assert_eq!(internship.status, InternshipStatus::Submitted);
+        applications.push(internship.id);
+    }
+
+    let move_to = |id: Uuid, status: &str| {
+        test::TestRequest::put()
+            .uri(&format!("/internship/{}/status", id))
+            .set_json(json!({ "status": status }))
+            .to_request()
+    };
+    for id in &applications {
+        let resp = test::call_service(&app, move_to(*id, "UnderReview")).await;
+        assert_eq!(resp.status(), 200);
+    }
+
+    // The first acceptance takes the only seat, the second one is refused
+    let resp = test::call_service(&app, move_to(applications[0], "Accepted")).await;
+    assert_eq!(resp.status(), 200);
+    let resp = test::call_service(&app, move_to(applications[1], "Accepted")).await;
+    assert_eq!(resp.status(), 409);
+    let req = test::TestRequest::get()
+        .uri(&format!("/internship/{}", applications[1]))
+        .to_request();
+    let bob: Internship = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(bob.status, InternshipStatus::UnderReview);
+
+    // A declined application never held a seat: the offer stays full, hence hidden
+    let resp = test::call_service(&app, move_to(applications[1], "Declined")).await;
+    assert_eq!(resp.status(), 200);
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(json!({ "student_id": students[2], "offer_id": offer_id }))
+        .to_request();
+    let carol: Internship = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(carol.status, InternshipStatus::Declined);
+    assert_eq!(carol.reasons[0].rule, "offer_available");
+}
+
+#[actix_web::test]
+async fn test_application_declined_when_offer_is_full() {
+    let student_repo_for_service = InMemoryStudentRepository::new();
+    let offer_client = MockOfferClient::new();
+
+    let student = Student {
+        id: Uuid::new_v4(),
+        firstname: "Alice".to_string(),
+        name: "Dupont".to_string(),
+        domain: "IT".to_string(),
+        email: None,
+        phone: None,
+        study_year: None,
+        languages: vec![],
+        cv: None,
+        version: 1,
+    };
+    student_repo_for_service.save(&student).await.unwrap();
+
+    // Still listed as available, but every seat is already taken by an accepted application
+    let offer_id = Uuid::new_v4();
+    offer_client.add_offer(Offer {
+        reserved_seats: 1,
+        ..make_test_offer(offer_id, "IT")
+    });
+
+    let state = web::Data::new(AppState {
+        student_repo: Box::new(InMemoryStudentRepository::new()),
+        domain_client: Box::new(MockDomainClient::new()),
+        internship_service: InternshipService::new(
+            Box::new(student_repo_for_service),
+            Box::new(InMemoryInternshipRepository::new()),
+            Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
+            std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
+        ),
+        notification_repo: Box::new(InMemoryNotificationRepository::new()),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
+    });
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(json!({ "student_id": student.id, "offer_id": offer_id }))
+        .to_request();
+    let body: Internship = test::call_and_read_body_json(&app, req).await;
+
+    assert_eq!(body.status, InternshipStatus::Declined);
+    assert_eq!(body.reasons[0].rule, "seat_available");
+}
+
+// ─── GET /student/{id}/internships, GET /internship ───
+
+#[actix_web::test]
+async fn test_list_internships_of_student_and_by_offer() {
+    let (state, student_id, offer_id) = state_with_student_and_offer().await;
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(json!({ "student_id": student_id, "offer_id": offer_id }))
+        .to_request();
+    let created: Internship = test::call_and_read_body_json(&app, req).await;
+
+    // A withdrawal and a new application: two internships, listed one per page
+    let req = test::TestRequest::put()
+        .uri(&format!("/internship/{}/status", created.id))
+        .set_json(json!({ "status": "Withdrawn" }))
+        .to_request();
+    test::call_service(&app, req).await;
+    let req = test::TestRequest::post()
+        .uri("/internship")
+        .set_json(json!({ "student_id": student_id, "offer_id": offer_id }))
+        .to_request();
+    let reapplied: Internship = test::call_and_read_body_json(&app, req).await;
+
+    let mut listed = Vec::new();
+    let mut uri = format!("/student/{}/internships?limit=1", student_id);
+    loop {
+        let req = test::TestRequest::get().uri(&uri).to_request();
+        let resp = test::call_service(&app, req).await;
+        assert_eq!(resp.status(), 200);
+        let page: Page<Internship> = test::read_body_json(resp).await;
+        assert!(page.items.len() <= 1);
+        listed.extend(page.items.into_iter().map(|i| i.id));
+        match page.next_cursor {
+            Some(cursor) => {
+                uri = format!(
+                    "/student/{}/internships?limit=1&cursor={}",
+                    student_id, cursor
+                )
+            }
+            None => break,
+        }
+    }
+    let mut expected = vec![created.id, reapplied.id];
+    expected.sort();
+    assert_eq!(listed, expected);
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/internship?offer_id={}&status=Submitted",
+            offer_id
+        ))
+        .to_request();
+    let body: Page<Internship> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 1);
+    assert_eq!(body.items[0].id, reapplied.id);
+    assert!(body.next_cursor.is_none());
+
+    let req = test::TestRequest::get()
+        .uri(&format!(
+            "/internship?offer_id={}&status=Accepted",
+            offer_id
+        ))
+        .to_request();
+    let body: Page<Internship> = test::call_and_read_body_json(&app, req).await;
+    assert!(body.items.is_empty());
+}
+
+#[actix_web::test]
+async fn test_list_internships_of_unknown_student_returns_404() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri(&format!("/student/{}/internships", Uuid::new_v4()))
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 404);
+}
+
+#[actix_web::test]
+async fn test_list_internships_rejects_invalid_limit() {
+    let state = test_app_state();
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::get()
+        .uri("/internship?limit=0")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+
+    assert_eq!(resp.status(), 400);
+}
+
+// ─── GET /offer ───
+
+#[actix_web::test]
+async fn test_aggregated_offers_embed_their_company() {
+    let offer_client = MockOfferClient::new();
+    let company = Company {
+        id: Uuid::new_v4(),
+        name: "Rustacean GmbH".to_string(),
+        country: "Germany".to_string(),
+        city: "Berlin".to_string(),
+        website: None,
+        contact: Some("jobs@rustacean.example".to_string()),
+        verified: true,
+    };
+    offer_client.add_company(company.clone());
+    let owned = Offer {
+        company_id: Some(company.id),
+        ..make_test_offer(Uuid::new_v4(), "IT")
+    };
+    offer_client.add_offer(owned.clone());
+    // The company was removed from the registry since: the offer is still listed
+    let orphan = Offer {
+        company_id: Some(Uuid::new_v4()),
+        ..make_test_offer(Uuid::new_v4(), "IT")
+    };
+    offer_client.add_offer(orphan.clone());
+
+    let state = web::Data::new(AppState {
+        student_repo: Box::new(InMemoryStudentRepository::new()),
+        domain_client: Box::new(MockDomainClient::new()),
+        internship_service: InternshipService::new(
+            Box::new(InMemoryStudentRepository::new()),
+            Box::new(InMemoryInternshipRepository::new()),
+            Box::new(offer_client),
+            Box::new(MockDomainClient::new()),
+            std::sync::Arc::new(mock_intelligence_client::MockIntelligenceClient::new()),
+            EligibilityPolicy::default(),
+        ),
+        notification_repo: Box::new(InMemoryNotificationRepository::new()),
+        subscriber_client: Box::new(MockSubscriberClient::new()),
+        file_storage: Box::new(InMemoryFileStorage::new()),
+        rabbitmq_channel: None,
+        rabbitmq_conn: None,
+    });
+    let app = test::init_service(test_app(state)).await;
+
+    let req = test::TestRequest::get().uri("/offer").to_request();
+    let body: Page<AggregatedOffer> = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(body.items.len(), 2);
+
+    let summary_of = |id: Uuid| {
+        body.items
+            .iter()
+            .find(|a| a.offer.id == id)
+            .map(|a| a.company.clone())
+            .unwrap()
+    };
+    let summary = summary_of(owned.id).expect("company summary");
+    assert_eq!(summary.name, "Rustacean GmbH");
+    assert!(summary.verified);
+    assert!(summary_of(orphan.id).is_none());
+}
@@ -1,20 +1,34 @@
+use chrono::Utc;
 use futures::future::join_all;
 use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use crate::domain::eligibility::{EligibilityContext, EligibilityPolicy, EligibilityViolation};
+use crate::domain::entities::company::CompanySummary;
 use crate::domain::entities::gateway::AggregatedOffer;
-use crate::domain::entities::internship::{CreateInternshipRequest, Internship, InternshipStatus};
+use crate::domain::entities::internship::{
+    CreateInternshipRequest, Internship, InternshipFilter, InternshipStatus,
+    InternshipStatusChange, UpdateInternshipStatusRequest,
+};
+use crate::domain::entities::offer::{Offer, OfferFilter};
+use crate::domain::pagination::{Page, PageRequest};
 use crate::domain::ports::{
-    DomainError, IntelligenceClient, InternshipRepository, OfferClient, StudentRepository,
+    DomainClient, DomainError, IntelligenceClient, InternshipRepository, OfferClient,
+    StudentRepository,
 };
 
+/// Nombre de tentatives de libération d'une place avant d'abandonner.
+const RELEASE_ATTEMPTS: u32 = 3;
+
 /// Service d'orchestration pour l'inscription aux stages et passerelle d'API.
 pub struct InternshipService {
     student_repo: Box<dyn StudentRepository>,
     internship_repo: Box<dyn InternshipRepository>,
     offer_client: Box<dyn OfferClient>,
+    domain_client: Box<dyn DomainClient>,
     intelligence_client: Arc<dyn IntelligenceClient>,
+    eligibility_policy: EligibilityPolicy,
 }
 
 impl InternshipService {
@@ -22,57 +36,90 @@ impl InternshipService {
         student_repo: Box<dyn StudentRepository>,
         internship_repo: Box<dyn InternshipRepository>,
         offer_client: Box<dyn OfferClient>,
+        domain_client: Box<dyn DomainClient>,
         intelligence_client: Arc<dyn IntelligenceClient>,
+        eligibility_policy: EligibilityPolicy,
     ) -> Self {
         Self {
             student_repo,
             internship_repo,
             offer_client,
+            domain_client,
             intelligence_client,
+            eligibility_policy,
         }
     }
 
     /// Processus d'inscription :
+    /// 0. Retourner la candidature existante si l'étudiant a déjà candidaté à cette offre,
+    ///    sauf si elle a été refusée ou retirée
     /// 1. Récupérer l'offre via le client HTTP (Erasmumu)
     /// 2. Récupérer l'étudiant depuis la BDD locale
-    /// 3. Valider la correspondance des domaines
-    /// 4. Sauvegarder et retourner le résultat
+    /// 3. Évaluer les règles d'éligibilité configurées (catalogue des domaines d'Erasmumu)
+    /// 4. Vérifier qu'il reste une place : elles sont réservées à l'acceptation (`update_status`)
+    /// 5. Sauvegarder la candidature (Submitted, ou Declined avec les raisons du refus)
     pub async fn register(
         &self,
         request: CreateInternshipRequest,
     ) -> Result<Internship, DomainError> {
+        // 0. Une seule candidature vivante par couple (étudiant, offre)
+        if let Some(existing) = self
+            .internship_repo
+            .find_by_student_and_offer(request.student_id, request.offer_id)
+            .await?
+        {
+            return Ok(existing);
+        }
+
         // 1. Récupérer l'offre depuis Erasmumu
         let offer = self.offer_client.get_offer_by_id(request.offer_id).await?;
 
         // 2. Récupérer l'étudiant
         let student = self.student_repo.find_by_id(request.student_id).await?;
 
-        // 3. Valider la correspondance des domaines
-        let (status, message) = if offer.domain.to_lowercase() == student.domain.to_lowercase() {
+        // 3. Évaluer les règles d'éligibilité
+        let student_internships = self.all_student_internships(student.id).await?;
+        let taxonomy = self.domain_client.get_taxonomy().await?;
+        let mut reasons = self.eligibility_policy.evaluate(&EligibilityContext {
+            student: &student,
+            offer: &offer,
+            student_internships: &student_internships,
+            taxonomy: &taxonomy,
+            today: Utc::now().date_naive(),
+        });
+
+        // 4. Toutes les places sont déjà prises par des candidatures acceptées
+        if reasons.is_empty() && offer.remaining_seats() == 0 {
+            reasons.push(EligibilityViolation {
+                rule: "seat_available".to_string(),
+                message: "l'offre est complète".to_string(),
+            });
+        }
+
+        let (status, message) = if reasons.is_empty() {
             (
-                InternshipStatus::Approved,
+                InternshipStatus::Submitted,
                 format!(
-                    "Inscription approuvée : {} {} pour l'offre '{}'",
+                    "Candidature soumise : {} {} pour l'offre '{}'",
                     student.firstname, student.name, offer.title
                 ),
             )
         } else {
+            let details: Vec<&str> = reasons.iter().map(|r| r.message.as_str()).collect();
             (
-                InternshipStatus::Rejected,
-                format!(
-                    "Inscription rejetée : le domaine de l'étudiant ({}) ne correspond pas au domaine de l'offre ({})",
-                    student.domain, offer.domain
-                ),
+                InternshipStatus::Declined,
+                format!("Inscription rejetée : {}", details.join(" ; ")),
             )
         };
 
-        // 4. Sauvegarder le résultat
+        // 5. Sauvegarder le résultat
         let internship = Internship {
             id: Uuid::new_v4(),
             student_id: request.student_id,
             offer_id: request.offer_id,
             status,
             message,
+            reasons,
         };
 
         self.internship_repo.save(&internship).await
@@ -83,20 +130,161 @@ impl InternshipService {
         self.internship_repo.find_by_id(id).await
     }
 
+    /// Liste une page des candidatures d'un étudiant.
+    pub async fn find_by_student(
+        &self,
+        student_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        self.student_repo.find_by_id(student_id).await?;
+        self.internship_repo.find_by_student(student_id, page).await
+    }
+
+    /// Toutes les candidatures d'un étudiant, lues page par page.
+    async fn all_student_internships(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<Internship>, DomainError> {
+        let mut internships = Vec::new();
+        let mut page = PageRequest::first::<Internship>(PageRequest::MAX_LIMIT);
+        loop {
+            let batch = self
+                .internship_repo
+                .find_by_student(student_id, &page)
+                .await?;
+            internships.extend(batch.items);
+            match batch.next_cursor {
+                Some(cursor) => {
+                    page = PageRequest::parse::<Internship>(
+                        Some(PageRequest::MAX_LIMIT),
+                        Some(&cursor),
+                        None,
+                    )?
+                }
+                None => return Ok(internships),
+            }
+        }
+    }
+
+    /// Recherche une page des candidatures selon les critères fournis.
+    pub async fn search(
+        &self,
+        filter: &InternshipFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError> {
+        self.internship_repo.search(filter, page).await
+    }
+
+    /// Fait avancer une candidature dans son cycle de vie.
+    /// Les transitions non autorisées sont refusées avec une erreur de validation.
+    /// L'acceptation réserve une place sur l'offre ; elle est refusée (`Conflict`) si l'offre est complète.
+    pub async fn update_status(
+        &self,
+        id: Uuid,
+        request: UpdateInternshipStatusRequest,
+    ) -> Result<Internship, DomainError> {
+        let internship = self.internship_repo.find_by_id(id).await?;
+
+        if !internship.status.can_transition_to(&request.status) {
+            return Err(DomainError::ValidationError(format!(
+                "Transition de statut invalide : {} → {}",
+                internship.status, request.status
+            )));
+        }
+
+        let accepted = request.status == InternshipStatus::Accepted;
+        let change = InternshipStatusChange {
+            internship_id: id,
+            message: request
+                .message
+                .unwrap_or_else(|| format!("Statut mis à jour : {}", request.status)),
+            from: Some(internship.status.clone()),
+            to: request.status,
+            changed_at: Utc::now(),
+        };
+
+        // Seule une candidature acceptée tient une place : la réserver avant d'enregistrer l'acceptation
+        if accepted {
+            match self.offer_client.reserve_seat(internship.offer_id).await {
+                Ok(_) => {}
+                Err(DomainError::Conflict(_)) => {
+                    return Err(DomainError::Conflict(format!(
+                        "L'offre {} est complète : la candidature {} ne peut pas être acceptée",
+                        internship.offer_id, id
+                    )));
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        match self.internship_repo.update_status(&change).await {
+            Ok(updated) => Ok(updated),
+            Err(e) => {
+                // Ne pas garder une place pour une acceptation qui n'a pas été enregistrée
+                if accepted {
+                    self.release_seat_of(&internship).await;
+                }
+                Err(e)
+            }
+        }
+    }
+
+    /// Libère la place réservée pour une candidature, en réessayant si Erasmumu ne répond pas.
+    /// Après `RELEASE_ATTEMPTS` échecs, la place reste réservée et l'échec est journalisé
+    /// pour être corrigé à la main (`POST /offer/{id}/release`).
+    async fn release_seat_of(&self, internship: &Internship) {
+        for attempt in 1..=RELEASE_ATTEMPTS {
+            match self.offer_client.release_seat(internship.offer_id).await {
+                // Offre supprimée, ou aucune place réservée : rien à libérer
+                Ok(_) | Err(DomainError::NotFound(_)) | Err(DomainError::Conflict(_)) => return,
+                Err(e) if attempt == RELEASE_ATTEMPTS => log::error!(
+                    "Seat of offer {} still reserved for internship {} ({}): {}",
+                    internship.offer_id,
+                    internship.id,
+                    internship.status,
+                    e
+                ),
+                Err(e) => log::warn!(
+                    "Releasing seat of offer {} failed (attempt {}): {}",
+                    internship.offer_id,
+                    attempt,
+                    e
+                ),
+            }
+        }
+    }
+
+    /// Libère la place tenue par une candidature effacée avec son étudiant.
+    /// Seule une candidature acceptée tient une place. Retourne `true` si une place a été libérée.
+    pub async fn release_erased_seat(&self, internship: &Internship) -> Result<bool, DomainError> {
+        if internship.status != InternshipStatus::Accepted {
+            return Ok(false);
+        }
+        match self.offer_client.release_seat(internship.offer_id).await {
+            Ok(_) => Ok(true),
+            Err(DomainError::NotFound(_)) => Ok(false),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Récupère l'historique des statuts d'une candidature, du plus ancien au plus récent.
+    pub async fn find_history(&self, id: Uuid) -> Result<Vec<InternshipStatusChange>, DomainError> {
+        self.internship_repo.find_by_id(id).await?;
+        self.internship_repo.find_history(id).await
+    }
+
     /// Passerelle d'API : Agrégation d'offres et de renseignements (Erasmumu + MI8)
     pub async fn get_aggregated_offers(
         &self,
-        domain: Option<String>,
-        city: Option<String>,
-    ) -> Result<Vec<AggregatedOffer>, DomainError> {
-        // 1. Fetch offers from Erasmumu
-        let offers = self.offer_client.search_offers(domain, city).await?;
-
-        // Optionnel : Limiter pour ne pas exploser la mémoire ?
-        // offers.truncate(limit.unwrap_or(20) as usize);
+        filter: &OfferFilter,
+        page: &PageRequest,
+    ) -> Result<Page<AggregatedOffer>, DomainError> {
+        // 1. Fetch one page of offers from Erasmumu
+        filter.validate()?;
+        let offers = self.offer_client.search_offers(filter, page).await?;
 
         // 2. Identify unique cities
-        let unique_cities: HashSet<String> = offers.iter().map(|o| o.city.clone()).collect();
+        let unique_cities: HashSet<String> = offers.items.iter().map(|o| o.city.clone()).collect();
 
         // 3. Fetch intelligence data concurrently for all unique cities
         let mut city_futures = Vec::new();
@@ -125,31 +313,48 @@ impl InternshipService {
             intelligence_map.insert(cname.clone(), (score, news));
         }
 
-        // 4. Aggregate data
-        let aggregated = offers
+        // 4. Fetch the companies of the page once each; an unknown company is left out
+        let unique_companies: HashSet<Uuid> =
+            offers.items.iter().filter_map(|o| o.company_id).collect();
+        let company_results = join_all(
+            unique_companies
+                .into_iter()
+                .map(|id| async move { (id, self.offer_client.get_company(id).await.ok()) }),
+        )
+        .await;
+        let company_map: HashMap<Uuid, CompanySummary> = company_results
             .into_iter()
-            .map(|offer| {
-                let (city_score, latest_news) = intelligence_map
-                    .get(&offer.city)
-                    .cloned()
-                    .unwrap_or((None, vec![]));
-
-                AggregatedOffer {
-                    offer,
-                    city_score,
-                    latest_news: if latest_news.is_empty() {
-                        None
-                    } else {
-                        Some(latest_news)
-                    },
-                }
-            })
+            .filter_map(|(id, company)| Some((id, CompanySummary::from(&company?))))
             .collect();
 
+        // 5. Aggregate data
+        let aggregated = offers.map(|offer| {
+            let (city_score, latest_news) = intelligence_map
+                .get(&offer.city)
+                .cloned()
+                .unwrap_or((None, vec![]));
+
+            let company = offer
+                .company_id
+                .and_then(|id| company_map.get(&id).cloned());
+
+            AggregatedOffer {
+                offer,
+                company,
+                city_score,
+                latest_news: if latest_news.is_empty() {
+                    None
+                } else {
+                    Some(latest_news)
+                },
+            }
+        });
+
         Ok(aggregated)
     }
 
     /// Récupère les offres recommandées pour un étudiant (filtrées par domaine, triées par score global de la ville)
+    /// parmi les `PageRequest::MAX_LIMIT` premières offres de son domaine.
     pub async fn get_recommended_offers_for_student(
         &self,
         student_id: Uuid,
@@ -157,8 +362,20 @@ impl InternshipService {
         let student = self.student_repo.find_by_id(student_id).await?;
 
         let mut aggregated = self
-            .get_aggregated_offers(Some(student.domain), None)
-            .await?;
+            .get_aggregated_offers(
+                &OfferFilter {
+                    domain: Some(student.domain),
+                    ..Default::default()
+                },
+                &PageRequest::first::<Offer>(PageRequest::MAX_LIMIT),
+            )
+            .await?
+            .items;
+
+        // Erasmumu n'expire les offres que périodiquement : on écarte celles dont la date
+        // limite est déjà passée
+        let today = Utc::now().date_naive();
+        aggregated.retain(|a| !a.offer.is_expired(today));
 
         // Tri descendant par le total du score de la ville
         aggregated.sort_by(|a, b| {
@@ -0,0 +1,75 @@
+use super::ports::DomainError;
+
+/// ETag d'une ressource versionnée : sa version, entre guillemets.
+pub fn etag(version: u64) -> String {
+    format!("\"{}\"", version)
+}
+
+/// Condition `If-Match` d'une mise à jour.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IfMatch {
+    /// `*` : toute version convient.
+    Any,
+    /// Liste d'ETags, dont l'un doit être celui de la version courante.
+    Versions(Vec<u64>),
+}
+
+impl IfMatch {
+    /// Lit la valeur d'un en-tête `If-Match`. Les ETags faibles (`W/"3"`) ne désignent
+    /// jamais la version courante : la comparaison d'`If-Match` est stricte.
+    pub fn parse(header: &str) -> Result<Self, DomainError> {
+        if header.trim() == "*" {
+            return Ok(IfMatch::Any);
+        }
+        let mut versions = Vec::new();
+        for tag in header.split(',').map(str::trim) {
+            if tag.starts_with("W/") {
+                continue;
+            }
+            let version = tag
+                .strip_prefix('"')
+                .and_then(|t| t.strip_suffix('"'))
+                .and_then(|t| t.parse().ok())
+                .ok_or_else(|| DomainError::ValidationError(format!("Invalid ETag {}", tag)))?;
+            versions.push(version);
+        }
+        Ok(IfMatch::Versions(versions))
+    }
+
+    /// Retourne `PreconditionFailed` si la ressource a changé depuis la lecture du client.
+    pub fn check(&self, version: u64, resource: &str) -> Result<(), DomainError> {
+        match self {
+            IfMatch::Versions(versions) if !versions.contains(&version) => {
+                Err(DomainError::PreconditionFailed(format!(
+                    "{} was modified: current version is {}",
+                    resource,
+                    etag(version)
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_if_match_compares_strong_etags() {
+        let condition = IfMatch::parse("\"2\", W/\"3\"").unwrap();
+        assert!(condition.check(2, "Offer").is_ok());
+        assert!(matches!(
+            condition.check(3, "Offer"),
+            Err(DomainError::PreconditionFailed(_))
+        ));
+        assert!(IfMatch::parse(" * ").unwrap().check(7, "Offer").is_ok());
+    }
+
+    #[test]
+    fn test_malformed_etags_are_rejected() {
+        assert!(IfMatch::parse("3").is_err());
+        assert!(IfMatch::parse("\"three\"").is_err());
+        assert_eq!(etag(4), "\"4\"");
+    }
+}
@@ -0,0 +1,412 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::entities::domain::DomainTaxonomy;
+use super::entities::internship::Internship;
+use super::entities::offer::Offer;
+use super::entities::student::Student;
+use super::ports::DomainError;
+
+/// Raison structurée pour laquelle une candidature n'est pas éligible.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct EligibilityViolation {
+    pub rule: String,
+    pub message: String,
+}
+
+/// Données disponibles pour évaluer l'éligibilité d'une candidature.
+pub struct EligibilityContext<'a> {
+    pub student: &'a Student,
+    pub offer: &'a Offer,
+    /// Candidatures déjà déposées par l'étudiant, tous statuts confondus.
+    pub student_internships: &'a [Internship],
+    /// Catalogue des domaines, pour rapprocher domaines parents et enfants.
+    pub taxonomy: &'a DomainTaxonomy,
+    pub today: NaiveDate,
+}
+
+/// Règle métier appliquée lors de l'inscription à un stage.
+pub trait EligibilityRule: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn check(&self, ctx: &EligibilityContext<'_>) -> Option<EligibilityViolation>;
+}
+
+fn violation(rule: &dyn EligibilityRule, message: String) -> Option<EligibilityViolation> {
+    Some(EligibilityViolation {
+        rule: rule.name().to_string(),
+        message,
+    })
+}
+
+/// Le domaine de l'étudiant doit correspondre à celui de l'offre : même domaine (alias compris)
+/// ou domaine parent/enfant dans le catalogue, sans tenir compte de la casse ni des accents.
+pub struct DomainMatchRule;
+
+impl EligibilityRule for DomainMatchRule {
+    fn name(&self) -> &'static str {
+        "domain_match"
+    }
+
+    fn check(&self, ctx: &EligibilityContext<'_>) -> Option<EligibilityViolation> {
+        if ctx.taxonomy.matches(&ctx.offer.domain, &ctx.student.domain) {
+            return None;
+        }
+        violation(
+            self,
+            format!(
+                "le domaine de l'étudiant ({}) ne correspond pas au domaine de l'offre ({})",
+                ctx.student.domain, ctx.offer.domain
+            ),
+        )
+    }
+}
+
+/// Le stage ne doit pas avoir déjà commencé.
+pub struct StartDateNotPassedRule;
+
+impl EligibilityRule for StartDateNotPassedRule {
+    fn name(&self) -> &'static str {
+        "start_date_not_passed"
+    }
+
+    fn check(&self, ctx: &EligibilityContext<'_>) -> Option<EligibilityViolation> {
+        if ctx.offer.start_date >= ctx.today {
+            return None;
+        }
+        violation(
+            self,
+            format!("le stage a déjà commencé le {}", ctx.offer.start_date),
+        )
+    }
+}
+
+/// L'offre doit être disponible et sa date limite de candidature non dépassée.
+pub struct OfferAvailableRule;
+
+impl EligibilityRule for OfferAvailableRule {
+    fn name(&self) -> &'static str {
+        "offer_available"
+    }
+
+    fn check(&self, ctx: &EligibilityContext<'_>) -> Option<EligibilityViolation> {
+        // L'expiration n'est appliquée que périodiquement : la date limite est vérifiée ici aussi
+        if ctx.offer.available && !ctx.offer.is_expired(ctx.today) {
+            return None;
+        }
+        violation(self, "l'offre n'est plus disponible".to_string())
+    }
+}
+
+/// Un étudiant ne peut avoir qu'une seule candidature en cours par offre.
+pub struct OneActiveApplicationPerOfferRule;
+
+impl EligibilityRule for OneActiveApplicationPerOfferRule {
+    fn name(&self) -> &'static str {
+        "one_active_application_per_offer"
+    }
+
+    fn check(&self, ctx: &EligibilityContext<'_>) -> Option<EligibilityViolation> {
+        let already_applied = ctx
+            .student_internships
+            .iter()
+            .any(|i| i.offer_id == ctx.offer.id && !i.status.is_final());
+        if !already_applied {
+            return None;
+        }
+        violation(
+            self,
+            "une candidature est déjà en cours pour cette offre".to_string(),
+        )
+    }
+}
+
+/// Limite le nombre de candidatures en cours simultanément pour un étudiant.
+pub struct MaxConcurrentApplicationsRule {
+    pub max: usize,
+}
+
+impl EligibilityRule for MaxConcurrentApplicationsRule {
+    fn name(&self) -> &'static str {
+        "max_concurrent_applications"
+    }
+
+    fn check(&self, ctx: &EligibilityContext<'_>) -> Option<EligibilityViolation> {
+        let active = ctx
+            .student_internships
+            .iter()
+            .filter(|i| !i.status.is_final())
+            .count();
+        if active < self.max {
+            return None;
+        }
+        violation(
+            self,
+            format!(
+                "l'étudiant a déjà {} candidatures en cours (maximum {})",
+                active, self.max
+            ),
+        )
+    }
+}
+
+/// Ensemble ordonné de règles d'éligibilité configuré par déploiement.
+pub struct EligibilityPolicy {
+    rules: Vec<Box<dyn EligibilityRule>>,
+}
+
+impl EligibilityPolicy {
+    pub fn new(rules: Vec<Box<dyn EligibilityRule>>) -> Self {
+        Self { rules }
+    }
+
+    /// Construit une politique à partir d'une liste séparée par des virgules, ex. :
+    /// `domain_match,offer_available,max_concurrent_applications=3`.
+    pub fn from_config(spec: &str) -> Result<Self, DomainError> {
+        let mut rules: Vec<Box<dyn EligibilityRule>> = Vec::new();
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (name, arg) = match entry.split_once('=') {
+                Some((name, arg)) => (name.trim(), Some(arg.trim())),
+                None => (entry, None),
+            };
+            let rule: Box<dyn EligibilityRule> = match (name, arg) {
+                ("domain_match", None) => Box::new(DomainMatchRule),
+                ("start_date_not_passed", None) => Box::new(StartDateNotPassedRule),
+                ("offer_available", None) => Box::new(OfferAvailableRule),
+                ("one_active_application_per_offer", None) => {
+                    Box::new(OneActiveApplicationPerOfferRule)
+                }
+                ("max_concurrent_applications", Some(max)) => {
+                    Box::new(MaxConcurrentApplicationsRule {
+                        max: max.parse().map_err(|_| {
+                            DomainError::ValidationError(format!(
+                                "Invalid max_concurrent_applications value: {}",
+                                max
+                            ))
+                        })?,
+                    })
+                }
+                _ => {
+                    return Err(DomainError::ValidationError(format!(
+                        "Unknown eligibility rule: {}",
+                        entry
+                    )));
+                }
+            };
+            rules.push(rule);
+        }
+        Ok(Self { rules })
+    }
+
+    /// Évalue toutes les règles et retourne l'ensemble des violations.
+    pub fn evaluate(&self, ctx: &EligibilityContext<'_>) -> Vec<EligibilityViolation> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.check(ctx))
+            .collect()
+    }
+}
+
+impl Default for EligibilityPolicy {
+    fn default() -> Self {
+        Self::new(vec![
+            Box::new(DomainMatchRule),
+            Box::new(OfferAvailableRule),
+            Box::new(StartDateNotPassedRule),
+            Box::new(OneActiveApplicationPerOfferRule),
+            Box::new(MaxConcurrentApplicationsRule { max: 5 }),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::domain::Domain;
+    use crate::domain::entities::internship::InternshipStatus;
+    use crate::domain::entities::money::{Money, SalaryPeriod};
+    use crate::domain::entities::offer::RemotePolicy;
+    use uuid::Uuid;
+
+    fn student(domain: &str) -> Student {
+        Student {
+            id: Uuid::new_v4(),
+            firstname: "Alice".to_string(),
+            name: "Dupont".to_string(),
+            domain: domain.to_string(),
+            email: None,
+            phone: None,
+            study_year: None,
+            languages: vec![],
+            cv: None,
+            version: 1,
+        }
+    }
+
+    fn offer(domain: &str, start: NaiveDate, available: bool) -> Offer {
+        Offer {
+            id: Uuid::new_v4(),
+            title: "Stage".to_string(),
+            link: "http://example.com".to_string(),
+            city: "Paris".to_string(),
+            domain: domain.to_string(),
+            salary: Money::new(120_000, "EUR", SalaryPeriod::Monthly),
+            base_salary: Some(Money::new(120_000, "EUR", SalaryPeriod::Monthly)),
+            start_date: start,
+            end_date: start + chrono::Days::new(180),
+            available,
+            seats: 1,
+            reserved_seats: 0,
+            deleted_at: None,
+            application_deadline: None,
+            company_id: None,
+            description: None,
+            skills: vec![],
+            languages: vec![],
+            remote_policy: RemotePolicy::OnSite,
+            version: 1,
+        }
+    }
+
+    fn application(student: &Student, offer_id: Uuid, status: InternshipStatus) -> Internship {
+        Internship {
+            id: Uuid::new_v4(),
+            student_id: student.id,
+            offer_id,
+            status,
+            message: String::new(),
+            reasons: vec![],
+        }
+    }
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 9, 1).unwrap()
+    }
+
+    #[test]
+    fn test_default_policy_accepts_eligible_application() {
+        let s = student("IT");
+        let o = offer("it", today(), true);
+        let ctx = EligibilityContext {
+            student: &s,
+            offer: &o,
+            student_internships: &[],
+            taxonomy: &DomainTaxonomy::default(),
+            today: today(),
+        };
+        assert!(EligibilityPolicy::default().evaluate(&ctx).is_empty());
+    }
+
+    #[test]
+    fn test_all_violations_are_collected() {
+        let s = student("IT");
+        let o = offer("Business", today() - chrono::Days::new(1), false);
+        let ctx = EligibilityContext {
+            student: &s,
+            offer: &o,
+            student_internships: &[],
+            taxonomy: &DomainTaxonomy::default(),
+            today: today(),
+        };
+        let rules: Vec<String> = EligibilityPolicy::default()
+            .evaluate(&ctx)
+            .into_iter()
+            .map(|v| v.rule)
+            .collect();
+        assert_eq!(
+            rules,
+            vec!["domain_match", "offer_available", "start_date_not_passed"]
+        );
+    }
+
+    #[test]
+    fn test_domain_match_accepts_parent_and_child_domains() {
+        let it = Domain {
+            id: Uuid::new_v4(),
+            name: "IT".to_string(),
+            parent_id: None,
+            aliases: vec!["Informatique".to_string()],
+        };
+        let data = Domain {
+            id: Uuid::new_v4(),
+            name: "Data Science".to_string(),
+            parent_id: Some(it.id),
+            aliases: vec![],
+        };
+        let taxonomy = DomainTaxonomy::new(vec![it, data]);
+
+        let s = student("Informatique");
+        let o = offer("Data Science", today(), true);
+        let ctx = EligibilityContext {
+            student: &s,
+            offer: &o,
+            student_internships: &[],
+            taxonomy: &taxonomy,
+            today: today(),
+        };
+        assert!(DomainMatchRule.check(&ctx).is_none());
+
+        let o = offer("Business", today(), true);
+        let ctx = EligibilityContext { offer: &o, ..ctx };
+        assert!(DomainMatchRule.check(&ctx).is_some());
+    }
+
+    #[test]
+    fn test_active_application_on_same_offer_is_rejected() {
+        let s = student("IT");
+        let o = offer("IT", today(), true);
+        let existing = [application(&s, o.id, InternshipStatus::UnderReview)];
+        let ctx = EligibilityContext {
+            student: &s,
+            offer: &o,
+            student_internships: &existing,
+            taxonomy: &DomainTaxonomy::default(),
+            today: today(),
+        };
+        assert!(OneActiveApplicationPerOfferRule.check(&ctx).is_some());
+
+        let withdrawn = [application(&s, o.id, InternshipStatus::Withdrawn)];
+        let ctx = EligibilityContext {
+            student_internships: &withdrawn,
+            ..ctx
+        };
+        assert!(OneActiveApplicationPerOfferRule.check(&ctx).is_none());
+    }
+
+    #[test]
+    fn test_max_concurrent_applications() {
+        let s = student("IT");
+        let o = offer("IT", today(), true);
+        let existing = [
+            application(&s, Uuid::new_v4(), InternshipStatus::Submitted),
+            application(&s, Uuid::new_v4(), InternshipStatus::Interview),
+            application(&s, Uuid::new_v4(), InternshipStatus::Declined),
+        ];
+        let ctx = EligibilityContext {
+            student: &s,
+            offer: &o,
+            student_internships: &existing,
+            taxonomy: &DomainTaxonomy::default(),
+            today: today(),
+        };
+        assert!(
+            MaxConcurrentApplicationsRule { max: 2 }
+                .check(&ctx)
+                .is_some()
+        );
+        assert!(
+            MaxConcurrentApplicationsRule { max: 3 }
+                .check(&ctx)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_policy_from_config() {
+        let policy =
+            EligibilityPolicy::from_config("domain_match, max_concurrent_applications=2").unwrap();
+        assert_eq!(policy.rules.len(), 2);
+        assert!(EligibilityPolicy::from_config("unknown_rule").is_err());
+        assert!(EligibilityPolicy::from_config("max_concurrent_applications=abc").is_err());
+    }
+}
@@ -1,7 +1,12 @@
 pub mod city_score;
+pub mod company;
+pub mod domain;
+pub mod file;
 pub mod gateway;
 pub mod internship;
+pub mod money;
 pub mod news;
 pub mod notification;
 pub mod offer;
 pub mod student;
+pub mod subscriber;
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Entreprise proposant des offres de stage.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct Company {
+    pub id: Uuid,
+    pub name: String,
+    pub country: String,
+    pub city: String,
+    pub website: Option<String>,
+    /// Adresse ou téléphone de la personne à contacter.
+    pub contact: Option<String>,
+    /// Entreprise vérifiée par l'école.
+    #[serde(default)]
+    pub verified: bool,
+}
+
+/// Résumé d'une entreprise joint aux offres agrégées.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct CompanySummary {
+    pub id: Uuid,
+    pub name: String,
+    pub country: String,
+    pub city: String,
+    pub verified: bool,
+}
+
+impl From<&Company> for CompanySummary {
+    fn from(company: &Company) -> Self {
+        Self {
+            id: company.id,
+            name: company.name.clone(),
+            country: company.country.clone(),
+            city: company.city.clone(),
+            verified: company.verified,
+        }
+    }
+}
+
+/// Une entreprise est créée non vérifiée.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateCompanyRequest {
+    pub name: String,
+    pub country: String,
+    pub city: String,
+    pub website: Option<String>,
+    pub contact: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateCompanyRequest {
+    pub name: Option<String>,
+    pub country: Option<String>,
+    pub city: Option<String>,
+    pub website: Option<String>,
+    pub contact: Option<String>,
+    pub verified: Option<bool>,
+}
@@ -0,0 +1,277 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::domain::normalize::{canonical_key, same_key};
+use crate::domain::ports::DomainError;
+
+/// Domaine d'études du catalogue partagé par les étudiants et les offres.
+/// Un domaine peut avoir un parent (ex. « Data Science » sous « IT ») et des alias
+/// (ex. « Informatique » pour « IT »).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct Domain {
+    pub id: Uuid,
+    pub name: String,
+    pub parent_id: Option<Uuid>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+impl Domain {
+    /// Nom et alias : valeurs acceptées pour désigner ce domaine.
+    fn labels(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.name.as_str()).chain(self.aliases.iter().map(String::as_str))
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreateDomainRequest {
+    pub name: String,
+    pub parent_id: Option<Uuid>,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateDomainRequest {
+    pub name: Option<String>,
+    /// Absent : parent inchangé ; `null` : le domaine devient racine.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub parent_id: Option<Option<Uuid>>,
+    pub aliases: Option<Vec<String>>,
+}
+
+/// Distingue un champ présent à `null` d'un champ absent.
+pub(crate) fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    T::deserialize(deserializer).map(Some)
+}
+
+/// Vue hiérarchique du catalogue des domaines.
+#[derive(Debug, Clone, Default)]
+pub struct DomainTaxonomy {
+    domains: Vec<Domain>,
+}
+
+impl DomainTaxonomy {
+    pub fn new(domains: Vec<Domain>) -> Self {
+        Self { domains }
+    }
+
+    pub fn domains(&self) -> &[Domain] {
+        &self.domains
+    }
+
+    /// Retrouve un domaine par son nom ou l'un de ses alias (clé canonique).
+    pub fn resolve(&self, value: &str) -> Option<&Domain> {
+        self.domains
+            .iter()
+            .find(|d| d.labels().any(|label| same_key(label, value)))
+    }
+
+    /// Nom de référence du domaine désigné par `value`, refusé s'il est absent du catalogue.
+    /// Tant que le catalogue est vide, `value` est accepté tel quel, comme dans `matches`.
+    pub fn canonical_name(&self, value: &str) -> Result<String, DomainError> {
+        if self.domains.is_empty() {
+            return Ok(value.trim().to_string());
+        }
+        self.resolve(value)
+            .map(|d| d.name.clone())
+            .ok_or_else(|| DomainError::ValidationError(format!("Unknown domain: {}", value)))
+    }
+
+    /// Comme `canonical_name`, pour une mise à jour : le domaine `current` d'un enregistrement
+    /// antérieur au catalogue reste accepté tant qu'il n'est pas changé.
+    pub fn canonical_name_for_update(
+        &self,
+        value: &str,
+        current: &str,
+    ) -> Result<String, DomainError> {
+        match self.canonical_name(value) {
+            Err(_) if same_key(value, current) => Ok(current.to_string()),
+            result => result,
+        }
+    }
+
+    /// Indique si `ancestor` est un parent, direct ou non, de `id`.
+    fn is_ancestor(&self, ancestor: Uuid, id: Uuid) -> bool {
+        let mut current = self.parent_of(id);
+        // Borne le parcours au cas où le catalogue stocké contiendrait un cycle
+        for _ in 0..self.domains.len() {
+            match current {
+                Some(parent) if parent == ancestor => return true,
+                Some(parent) => current = self.parent_of(parent),
+                None => return false,
+            }
+        }
+        false
+    }
+
+    fn parent_of(&self, id: Uuid) -> Option<Uuid> {
+        self.domains
+            .iter()
+            .find(|d| d.id == id)
+            .and_then(|d| d.parent_id)
+    }
+
+    fn related(&self, a: Uuid, b: Uuid) -> bool {
+        a == b || self.is_ancestor(a, b) || self.is_ancestor(b, a)
+    }
+
+    /// Deux domaines correspondent s'ils sont identiques ou si l'un est un parent
+    /// (direct ou non) de l'autre. Hors catalogue, seule la clé canonique est comparée.
+    pub fn matches(&self, a: &str, b: &str) -> bool {
+        match (self.resolve(a), self.resolve(b)) {
+            (Some(a), Some(b)) => self.related(a.id, b.id),
+            _ => same_key(a, b),
+        }
+    }
+
+    /// Clés canoniques des noms et alias de tous les domaines correspondant à `value`.
+    pub fn related_keys(&self, value: &str) -> Vec<String> {
+        let Some(domain) = self.resolve(value) else {
+            return vec![canonical_key(value)];
+        };
+        let mut keys: Vec<String> = self
+            .domains
+            .iter()
+            .filter(|d| self.related(domain.id, d.id))
+            .flat_map(|d| d.labels().map(canonical_key))
+            .collect();
+        keys.sort();
+        keys.dedup();
+        keys
+    }
+
+    /// Vérifie qu'un domaine peut être enregistré dans le catalogue :
+    /// nom non vide, nom et alias non utilisés par un autre domaine,
+    /// parent existant et absence de cycle.
+    pub fn check(&self, domain: &Domain) -> Result<(), DomainError> {
+        if domain.name.trim().is_empty() {
+            return Err(DomainError::ValidationError(
+                "Domain name must not be empty".to_string(),
+            ));
+        }
+        for label in domain.labels() {
+            if let Some(other) = self.resolve(label).filter(|other| other.id != domain.id) {
+                return Err(DomainError::Conflict(format!(
+                    "'{}' is already used by domain {}",
+                    label, other.name
+                )));
+            }
+        }
+        if let Some(parent_id) = domain.parent_id {
+            if !self.domains.iter().any(|d| d.id == parent_id) {
+                return Err(DomainError::ValidationError(format!(
+                    "Parent domain {} not found",
+                    parent_id
+                )));
+            }
+            if parent_id == domain.id || self.is_ancestor(domain.id, parent_id) {
+                return Err(DomainError::ValidationError(format!(
+                    "Domain {} cannot be its own ancestor",
+                    domain.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Indique si des domaines sont rattachés à `id`.
+    pub fn has_children(&self, id: Uuid) -> bool {
+        self.domains.iter().any(|d| d.parent_id == Some(id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn domain(name: &str, parent: Option<&Domain>, aliases: &[&str]) -> Domain {
+        Domain {
+            id: Uuid::new_v4(),
+            name: name.to_string(),
+            parent_id: parent.map(|p| p.id),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    fn taxonomy() -> (DomainTaxonomy, Domain, Domain, Domain) {
+        let it = domain("IT", None, &["Informatique", "Computer Science"]);
+        let data = domain("Data Science", Some(&it), &[]);
+        let business = domain("Business", None, &[]);
+        let taxonomy = DomainTaxonomy::new(vec![it.clone(), data.clone(), business.clone()]);
+        (taxonomy, it, data, business)
+    }
+
+    #[test]
+    fn test_aliases_resolve_to_canonical_name() {
+        let (taxonomy, ..) = taxonomy();
+        assert_eq!(taxonomy.canonical_name("informatique").unwrap(), "IT");
+        assert_eq!(taxonomy.canonical_name("COMPUTER SCIENCE").unwrap(), "IT");
+        assert!(taxonomy.canonical_name("Astrologie").is_err());
+
+        // Without a catalog, any domain is accepted
+        assert_eq!(
+            DomainTaxonomy::default().canonical_name(" Santé ").unwrap(),
+            "Santé"
+        );
+        // A record keeps a domain missing from the catalog as long as it does not change it
+        assert_eq!(
+            taxonomy
+                .canonical_name_for_update("astrologie", "Astrologie")
+                .unwrap(),
+            "Astrologie"
+        );
+        assert_eq!(
+            taxonomy
+                .canonical_name_for_update("Informatique", "Astrologie")
+                .unwrap(),
+            "IT"
+        );
+        assert!(
+            taxonomy
+                .canonical_name_for_update("Astrologie", "IT")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_parent_and_child_domains_match() {
+        let (taxonomy, ..) = taxonomy();
+        assert!(taxonomy.matches("Informatique", "IT"));
+        assert!(taxonomy.matches("Data Science", "Computer Science"));
+        assert!(taxonomy.matches("IT", "data science"));
+        assert!(!taxonomy.matches("Data Science", "Business"));
+        assert!(DomainTaxonomy::default().matches("Santé", "sante"));
+
+        assert_eq!(
+            taxonomy.related_keys("Data Science"),
+            vec!["computer science", "data science", "informatique", "it"]
+        );
+    }
+
+    #[test]
+    fn test_check_rejects_duplicates_and_cycles() {
+        let (taxonomy, mut it, data, business) = taxonomy();
+        assert!(matches!(
+            taxonomy.check(&domain("informatique", None, &[])),
+            Err(DomainError::Conflict(_))
+        ));
+        assert!(matches!(
+            taxonomy.check(&domain("Finance", None, &["Business"])),
+            Err(DomainError::Conflict(_))
+        ));
+        assert!(
+            taxonomy
+                .check(&domain("Finance", Some(&business), &[]))
+                .is_ok()
+        );
+
+        it.parent_id = Some(data.id);
+        assert!(taxonomy.check(&it).is_err());
+    }
+}
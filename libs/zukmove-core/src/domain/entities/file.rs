@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Fichier déposé par un utilisateur, dont le contenu est conservé par `FileStorage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct StoredFile {
+    /// Clé du contenu dans le stockage.
+    pub key: String,
+    /// Nom du fichier tel que déposé, proposé au téléchargement.
+    pub filename: String,
+    pub content_type: String,
+    /// Taille en octets.
+    pub size: u64,
+    pub uploaded_at: DateTime<Utc>,
+}
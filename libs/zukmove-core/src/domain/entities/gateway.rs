@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
 use super::city_score::CityScore;
+use super::company::CompanySummary;
 use super::news::News;
 use super::offer::Offer;
 
@@ -10,4 +11,5 @@ pub struct AggregatedOffer {
     pub offer: Offer,
     pub city_score: Option<CityScore>,
     pub latest_news: Option<Vec<News>>,
+    pub company: Option<CompanySummary>,
 }
@@ -1,18 +1,62 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::eligibility::EligibilityViolation;
+use crate::domain::pagination::{Paginated, SortKey};
+
+/// Cycle de vie d'une candidature :
+/// Submitted → UnderReview → Interview → Accepted / Declined / Withdrawn.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub enum InternshipStatus {
-    Approved,
-    Rejected,
+    Submitted,
+    UnderReview,
+    Interview,
+    Accepted,
+    Declined,
+    Withdrawn,
+}
+
+impl InternshipStatus {
+    /// Une candidature dans un état final ne peut plus évoluer.
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            InternshipStatus::Accepted | InternshipStatus::Declined | InternshipStatus::Withdrawn
+        )
+    }
+
+    /// Une candidature refusée ou retirée a pris fin sans stage : l'étudiant peut candidater
+    /// de nouveau à l'offre.
+    pub fn releases_offer(&self) -> bool {
+        matches!(
+            self,
+            InternshipStatus::Declined | InternshipStatus::Withdrawn
+        )
+    }
+
+    /// Indique si la transition `self → next` est autorisée.
+    pub fn can_transition_to(&self, next: &InternshipStatus) -> bool {
+        use InternshipStatus::*;
+        matches!(
+            (self, next),
+            (Submitted, UnderReview | Declined | Withdrawn)
+                | (UnderReview, Interview | Accepted | Declined | Withdrawn)
+                | (Interview, Accepted | Declined | Withdrawn)
+        )
+    }
 }
 
 impl std::fmt::Display for InternshipStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            InternshipStatus::Approved => write!(f, "Approved"),
-            InternshipStatus::Rejected => write!(f, "Rejected"),
+            InternshipStatus::Submitted => write!(f, "Submitted"),
+            InternshipStatus::UnderReview => write!(f, "UnderReview"),
+            InternshipStatus::Interview => write!(f, "Interview"),
+            InternshipStatus::Accepted => write!(f, "Accepted"),
+            InternshipStatus::Declined => write!(f, "Declined"),
+            InternshipStatus::Withdrawn => write!(f, "Withdrawn"),
         }
     }
 }
@@ -22,8 +66,12 @@ impl std::str::FromStr for InternshipStatus {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
-            "Approved" => Ok(InternshipStatus::Approved),
-            "Rejected" => Ok(InternshipStatus::Rejected),
+            "Submitted" => Ok(InternshipStatus::Submitted),
+            "UnderReview" => Ok(InternshipStatus::UnderReview),
+            "Interview" => Ok(InternshipStatus::Interview),
+            "Accepted" => Ok(InternshipStatus::Accepted),
+            "Declined" => Ok(InternshipStatus::Declined),
+            "Withdrawn" => Ok(InternshipStatus::Withdrawn),
             _ => Err(format!("Invalid internship status: {}", s)),
         }
     }
@@ -36,6 +84,21 @@ pub struct Internship {
     pub offer_id: Uuid,
     pub status: InternshipStatus,
     pub message: String,
+    /// Raisons structurées du refus lorsque la candidature n'est pas éligible.
+    #[serde(default)]
+    pub reasons: Vec<EligibilityViolation>,
+}
+
+impl Paginated for Internship {
+    const SORT_FIELDS: &'static [&'static str] = &["id"];
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn sort_key(&self, _field: &str) -> SortKey {
+        SortKey::Text(self.id.to_string())
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -43,3 +106,91 @@ pub struct CreateInternshipRequest {
     pub student_id: Uuid,
     pub offer_id: Uuid,
 }
+
+/// Demande de changement de statut d'une candidature.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateInternshipStatusRequest {
+    pub status: InternshipStatus,
+    pub message: Option<String>,
+}
+
+/// Critères de recherche des candidatures, combinés par ET ; sans critère, toutes les candidatures.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InternshipFilter {
+    pub student_id: Option<Uuid>,
+    pub offer_id: Option<Uuid>,
+    pub status: Option<InternshipStatus>,
+}
+
+impl InternshipFilter {
+    /// Indique si une candidature satisfait les critères.
+    pub fn matches(&self, internship: &Internship) -> bool {
+        self.student_id.is_none_or(|id| internship.student_id == id)
+            && self.offer_id.is_none_or(|id| internship.offer_id == id)
+            && self
+                .status
+                .as_ref()
+                .is_none_or(|status| &internship.status == status)
+    }
+}
+
+/// Entrée de l'historique des statuts d'une candidature.
+/// `from` vaut `None` pour la création de la candidature.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InternshipStatusChange {
+    pub internship_id: Uuid,
+    pub from: Option<InternshipStatus>,
+    pub to: InternshipStatus,
+    pub message: String,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use InternshipStatus::*;
+
+    #[test]
+    fn test_happy_path_transitions() {
+        assert!(Submitted.can_transition_to(&UnderReview));
+        assert!(UnderReview.can_transition_to(&Interview));
+        assert!(Interview.can_transition_to(&Accepted));
+    }
+
+    #[test]
+    fn test_cannot_skip_review() {
+        assert!(!Submitted.can_transition_to(&Interview));
+        assert!(!Submitted.can_transition_to(&Accepted));
+    }
+
+    #[test]
+    fn test_final_states_are_frozen() {
+        for from in [Accepted, Declined, Withdrawn] {
+            assert!(from.is_final());
+            for to in [
+                Submitted,
+                UnderReview,
+                Interview,
+                Accepted,
+                Declined,
+                Withdrawn,
+            ] {
+                assert!(!from.can_transition_to(&to));
+            }
+        }
+    }
+
+    #[test]
+    fn test_status_round_trip() {
+        for status in [
+            Submitted,
+            UnderReview,
+            Interview,
+            Accepted,
+            Declined,
+            Withdrawn,
+        ] {
+            assert_eq!(status.to_string().parse::<InternshipStatus>(), Ok(status));
+        }
+    }
+}
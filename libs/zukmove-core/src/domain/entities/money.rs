@@ -0,0 +1,221 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::ports::DomainError;
+
+/// Devise des salaires enregistrés avant l'introduction de `Money`.
+pub const LEGACY_CURRENCY: &str = "EUR";
+
+/// Précision des taux de change : millionièmes d'unité.
+const RATE_SCALE: i128 = 1_000_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum SalaryPeriod {
+    #[default]
+    Monthly,
+    Yearly,
+}
+
+/// Montant dans une devise, pour une période donnée.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct Money {
+    /// Montant en unités mineures de la devise (centimes, pence…), jamais en flottant.
+    pub amount: i64,
+    /// Code ISO 4217 (`EUR`, `GBP`…).
+    pub currency: String,
+    #[serde(default)]
+    pub period: SalaryPeriod,
+}
+
+impl Money {
+    pub fn new(amount: i64, currency: &str, period: SalaryPeriod) -> Self {
+        Self {
+            amount,
+            currency: currency.to_string(),
+            period,
+        }
+    }
+
+    /// Salaire exprimé en unités de la devise, tel qu'il était stocké avant `Money`.
+    pub fn from_major(value: f64, currency: &str, period: SalaryPeriod) -> Self {
+        Self::new(minor_units(value), currency, period)
+    }
+
+    /// Montant ramené au mois, arrondi à l'unité mineure.
+    pub fn monthly(&self) -> Money {
+        match self.period {
+            SalaryPeriod::Monthly => self.clone(),
+            SalaryPeriod::Yearly => Money::new(
+                div_round(i128::from(self.amount), 12) as i64,
+                &self.currency,
+                SalaryPeriod::Monthly,
+            ),
+        }
+    }
+}
+
+/// Montant en unités de la devise converti en unités mineures, comme les montants stockés.
+pub fn minor_units(value: f64) -> i64 {
+    (value * 100.0).round() as i64
+}
+
+/// Accepte un `Money` ou, pour les offres et messages antérieurs, un nombre
+/// (salaire mensuel en euros).
+pub(crate) fn deserialize_salary<'de, D>(deserializer: D) -> Result<Money, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Salary {
+        Legacy(f64),
+        Money(Money),
+    }
+
+    Ok(match Salary::deserialize(deserializer)? {
+        Salary::Legacy(value) => Money::from_major(value, LEGACY_CURRENCY, SalaryPeriod::Monthly),
+        Salary::Money(money) => money,
+    })
+}
+
+/// Variante de `deserialize_salary` pour les champs facultatifs des mises à jour.
+pub(crate) fn deserialize_optional_salary<'de, D>(
+    deserializer: D,
+) -> Result<Option<Money>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserialize_salary(deserializer).map(Some)
+}
+
+/// Taux de change vers une devise de référence, dans laquelle les salaires sont comparés.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExchangeRates {
+    base: String,
+    /// Unités de chaque devise pour une unité de la devise de référence, en millionièmes.
+    rates: BTreeMap<String, i64>,
+}
+
+impl ExchangeRates {
+    pub fn new(base: &str, rates: BTreeMap<String, i64>) -> Result<Self, DomainError> {
+        if let Some((currency, _)) = rates.iter().find(|(_, rate)| **rate <= 0) {
+            return Err(DomainError::ValidationError(format!(
+                "Exchange rate of {} must be positive",
+                currency
+            )));
+        }
+        Ok(Self {
+            base: base.to_string(),
+            rates,
+        })
+    }
+
+    /// Taux exprimés en unités décimales, comme dans les fichiers publiés par les banques centrales.
+    pub fn from_decimal(
+        base: &str,
+        rates: impl IntoIterator<Item = (String, f64)>,
+    ) -> Result<Self, DomainError> {
+        let rates = rates
+            .into_iter()
+            .map(|(currency, rate)| (currency, (rate * RATE_SCALE as f64).round() as i64))
+            .collect();
+        Self::new(base, rates)
+    }
+
+    pub fn base(&self) -> &str {
+        &self.base
+    }
+
+    fn rate(&self, currency: &str) -> Result<i128, DomainError> {
+        if currency == self.base {
+            return Ok(RATE_SCALE);
+        }
+        self.rates
+            .get(currency)
+            .map(|rate| i128::from(*rate))
+            .ok_or_else(|| {
+                DomainError::ValidationError(format!("No exchange rate for currency {}", currency))
+            })
+    }
+
+    /// Convertit un montant dans une autre devise, à période égale.
+    pub fn convert(&self, money: &Money, currency: &str) -> Result<Money, DomainError> {
+        if money.currency == currency {
+            return Ok(money.clone());
+        }
+        let from = self.rate(&money.currency)?;
+        let to = self.rate(currency)?;
+        let amount = div_round(i128::from(money.amount) * to, from);
+        Ok(Money::new(amount as i64, currency, money.period))
+    }
+
+    /// Montant mensuel dans la devise de référence : la valeur comparée par les filtres et tris.
+    pub fn to_base(&self, money: &Money) -> Result<Money, DomainError> {
+        self.convert(&money.monthly(), &self.base)
+    }
+}
+
+/// Division entière arrondie au plus proche, demi vers l'infini.
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let half = denominator / 2;
+    if (numerator < 0) == (denominator < 0) {
+        (numerator + half) / denominator
+    } else {
+        (numerator - half) / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rates() -> ExchangeRates {
+        ExchangeRates::from_decimal(
+            "EUR",
+            [("GBP".to_string(), 0.85), ("CHF".to_string(), 0.95)],
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_salaries_are_compared_monthly_in_base_currency() {
+        let london = Money::new(2_040_000, "GBP", SalaryPeriod::Yearly);
+        assert_eq!(
+            rates().to_base(&london).unwrap(),
+            Money::new(200_000, "EUR", SalaryPeriod::Monthly)
+        );
+
+        let zurich = Money::new(190_000, "CHF", SalaryPeriod::Monthly);
+        assert_eq!(rates().convert(&zurich, "GBP").unwrap().amount, 170_000);
+
+        assert!(
+            rates()
+                .to_base(&Money::new(100, "JPY", SalaryPeriod::Monthly))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_legacy_salaries_are_monthly_euros() {
+        #[derive(Deserialize)]
+        struct Payload {
+            #[serde(deserialize_with = "deserialize_salary")]
+            salary: Money,
+        }
+
+        let legacy: Payload = serde_json::from_str(r#"{ "salary": 1234.5 }"#).unwrap();
+        assert_eq!(
+            legacy.salary,
+            Money::new(123_450, "EUR", SalaryPeriod::Monthly)
+        );
+
+        let current: Payload = serde_json::from_str(
+            r#"{ "salary": { "amount": 3000000, "currency": "GBP", "period": "yearly" } }"#,
+        )
+        .unwrap();
+        assert_eq!(current.salary.period, SalaryPeriod::Yearly);
+    }
+}
@@ -2,6 +2,8 @@ use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::pagination::{Paginated, SortKey};
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Notification {
     pub id: Uuid,
@@ -11,4 +13,19 @@ pub struct Notification {
     pub offer_id: Uuid,
     pub message: String,
     pub read: bool,
+    /// L'offre a été modifiée ou supprimée depuis l'envoi : le message n'est plus à jour.
+    #[serde(default)]
+    pub stale: bool,
+}
+
+impl Paginated for Notification {
+    const SORT_FIELDS: &'static [&'static str] = &["id"];
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn sort_key(&self, _field: &str) -> SortKey {
+        SortKey::Text(self.id.to_string())
+    }
 }
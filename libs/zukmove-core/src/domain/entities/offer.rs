@@ -1,8 +1,19 @@
-use chrono::NaiveDate;
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::entities::domain::deserialize_present;
+use crate::domain::entities::money::{
+    ExchangeRates, Money, SalaryPeriod, deserialize_optional_salary, deserialize_salary,
+    minor_units,
+};
+use crate::domain::normalize::same_key;
+use crate::domain::pagination::{Paginated, SortKey};
+use crate::domain::ports::DomainError;
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Offer {
     pub id: Uuid,
@@ -10,10 +21,166 @@ pub struct Offer {
     pub link: String,
     pub city: String,
     pub domain: String,
-    pub salary: f64,
+    #[serde(deserialize_with = "deserialize_salary")]
+    pub salary: Money,
+    /// Salaire mensuel dans la devise de référence, calculé à l'enregistrement : c'est
+    /// la valeur comparée par les filtres et le tri sur le salaire.
+    #[serde(default)]
+    pub base_salary: Option<Money>,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
     pub available: bool,
+    /// Nombre de stagiaires recherchés par l'entreprise.
+    #[serde(default = "default_seats")]
+    pub seats: u32,
+    /// Nombre de places déjà réservées par des candidatures.
+    #[serde(default)]
+    pub reserved_seats: u32,
+    /// Date de suppression : une offre supprimée est archivée, et non effacée, afin que
+    /// les candidatures qui la référencent restent consultables.
+    #[serde(default)]
+    pub deleted_at: Option<DateTime<Utc>>,
+    /// Date limite de candidature ; à défaut, l'offre expire à la fin du stage.
+    #[serde(default)]
+    pub application_deadline: Option<NaiveDate>,
+    /// Entreprise qui propose l'offre, absente des offres antérieures au référentiel.
+    #[serde(default)]
+    pub company_id: Option<Uuid>,
+    /// Présentation du poste, en Markdown.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Compétences attendues du stagiaire.
+    #[serde(default)]
+    pub skills: Vec<String>,
+    /// Langues exigées, chacune avec son niveau minimal.
+    #[serde(default)]
+    pub languages: Vec<LanguageRequirement>,
+    #[serde(default)]
+    pub remote_policy: RemotePolicy,
+    /// Incrémentée à chaque écriture ; sert d'ETag aux mises à jour concurrentes.
+    /// Vaut 0 pour les offres antérieures au versionnement.
+    #[serde(default)]
+    pub version: u64,
+}
+
+/// Niveau de langue du Cadre européen commun de référence, du plus faible au plus élevé.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ToSchema)]
+pub enum CefrLevel {
+    A1,
+    A2,
+    B1,
+    B2,
+    C1,
+    C2,
+}
+
+impl CefrLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CefrLevel::A1 => "A1",
+            CefrLevel::A2 => "A2",
+            CefrLevel::B1 => "B1",
+            CefrLevel::B2 => "B2",
+            CefrLevel::C1 => "C1",
+            CefrLevel::C2 => "C2",
+        }
+    }
+
+    /// Rang du niveau, croissant de A1 (0) à C2 (5).
+    pub fn rank(&self) -> u8 {
+        *self as u8
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct LanguageRequirement {
+    pub language: String,
+    pub level: CefrLevel,
+}
+
+/// Organisation du travail proposée par l'entreprise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RemotePolicy {
+    /// Valeur des offres antérieures à ce champ.
+    #[default]
+    OnSite,
+    Hybrid,
+    Remote,
+}
+
+impl RemotePolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RemotePolicy::OnSite => "on_site",
+            RemotePolicy::Hybrid => "hybrid",
+            RemotePolicy::Remote => "remote",
+        }
+    }
+}
+
+impl Offer {
+    pub fn remaining_seats(&self) -> u32 {
+        self.seats.saturating_sub(self.reserved_seats)
+    }
+
+    /// Change le nombre de places sans descendre sous les places déjà réservées (`Conflict`).
+    /// Une offre complète devient indisponible ; une offre masquée parce qu'elle était complète
+    /// redevient disponible lorsqu'une place s'ouvre, comme lors d'une libération.
+    pub fn resize(&mut self, seats: u32) -> Result<(), DomainError> {
+        if seats < self.reserved_seats {
+            return Err(DomainError::Conflict(format!(
+                "Offer with id {} has {} reserved seat(s), cannot reduce it to {}",
+                self.id, self.reserved_seats, seats
+            )));
+        }
+        let was_full = self.remaining_seats() == 0;
+        self.seats = seats;
+        if self.remaining_seats() == 0 {
+            self.available = false;
+        } else if was_full {
+            self.available = true;
+        }
+        Ok(())
+    }
+
+    pub fn is_archived(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    /// Dernier jour où l'on peut candidater.
+    pub fn deadline(&self) -> NaiveDate {
+        self.application_deadline.unwrap_or(self.end_date)
+    }
+
+    pub fn is_expired(&self, today: NaiveDate) -> bool {
+        today > self.deadline()
+    }
+}
+
+impl Paginated for Offer {
+    const SORT_FIELDS: &'static [&'static str] = &["start_date", "title", "city", "salary"];
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn sort_key(&self, field: &str) -> SortKey {
+        match field {
+            "title" => SortKey::Text(self.title.clone()),
+            "city" => SortKey::Text(self.city.clone()),
+            "salary" => self
+                .base_salary
+                .as_ref()
+                .map_or(SortKey::Null, |m| SortKey::Number(m.amount as f64)),
+            // Stored as "YYYY-MM-DD": lexical order is chronological
+            _ => SortKey::Text(self.start_date.to_string()),
+        }
+    }
+}
+
+fn default_seats() -> u32 {
+    1
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -22,10 +189,26 @@ pub struct CreateOfferRequest {
     pub link: String,
     pub city: String,
     pub domain: String,
-    pub salary: f64,
+    /// Un nombre seul est lu comme un salaire mensuel en euros.
+    #[serde(deserialize_with = "deserialize_salary")]
+    pub salary: Money,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
     pub available: bool,
+    #[serde(default = "default_seats")]
+    pub seats: u32,
+    #[serde(default)]
+    pub application_deadline: Option<NaiveDate>,
+    #[serde(default)]
+    pub company_id: Option<Uuid>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub skills: Vec<String>,
+    #[serde(default)]
+    pub languages: Vec<LanguageRequirement>,
+    #[serde(default)]
+    pub remote_policy: RemotePolicy,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -34,8 +217,491 @@ pub struct UpdateOfferRequest {
     pub link: Option<String>,
     pub city: Option<String>,
     pub domain: Option<String>,
-    pub salary: Option<f64>,
+    #[serde(default, deserialize_with = "deserialize_optional_salary")]
+    pub salary: Option<Money>,
     pub start_date: Option<NaiveDate>,
     pub end_date: Option<NaiveDate>,
     pub available: Option<bool>,
+    pub seats: Option<u32>,
+    /// Absent : date limite inchangée ; `null` : l'offre expire à la fin du stage.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub application_deadline: Option<Option<NaiveDate>>,
+    /// Absent : entreprise inchangée ; `null` : l'offre n'est plus rattachée à une entreprise.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub company_id: Option<Option<Uuid>>,
+    /// Absent : description inchangée ; `null` : l'offre n'a plus de description.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub description: Option<Option<String>>,
+    /// Remplace toute la liste lorsqu'il est fourni.
+    #[serde(default)]
+    pub skills: Option<Vec<String>>,
+    /// Remplace toute la liste lorsqu'il est fourni.
+    #[serde(default)]
+    pub languages: Option<Vec<LanguageRequirement>>,
+    #[serde(default)]
+    pub remote_policy: Option<RemotePolicy>,
+}
+
+/// Offres concernées par une recherche selon leur disponibilité.
+/// Les routes publiques ne voient que les offres disponibles ; l'administration voit aussi
+/// les offres masquées et archivées.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OfferVisibility {
+    Available,
+    Hidden,
+    Archived,
+    All,
+}
+
+impl OfferVisibility {
+    pub fn includes(&self, offer: &Offer) -> bool {
+        match self {
+            OfferVisibility::Available => offer.available && !offer.is_archived(),
+            OfferVisibility::Hidden => !offer.available && !offer.is_archived(),
+            OfferVisibility::Archived => offer.is_archived(),
+            OfferVisibility::All => true,
+        }
+    }
+}
+
+/// Critères de recherche d'offres, combinés par ET. La disponibilité est choisie à part
+/// (voir `OfferVisibility`).
+/// Domaine et ville sont comparés par clé canonique (voir `normalize::canonical_key`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OfferFilter {
+    pub domain: Option<String>,
+    pub city: Option<String>,
+    /// Salaire mensuel minimal, en unités de `currency`.
+    pub min_salary: Option<f64>,
+    pub max_salary: Option<f64>,
+    /// Devise des bornes de salaire ; par défaut la devise de référence des taux.
+    pub currency: Option<String>,
+    /// Le stage commence au plus tôt à cette date.
+    pub start_after: Option<NaiveDate>,
+    /// Le stage se termine au plus tard à cette date.
+    pub end_before: Option<NaiveDate>,
+    /// Texte recherché dans le titre (insensible à la casse).
+    pub text: Option<String>,
+    pub company_id: Option<Uuid>,
+    /// Compétence attendue par l'offre.
+    pub skill: Option<String>,
+    /// Langue exigée par l'offre.
+    pub language: Option<String>,
+    /// Avec `language` : niveau du candidat, seules les offres exigeant au plus ce niveau
+    /// sont retenues.
+    pub language_level: Option<CefrLevel>,
+    pub remote_policy: Option<RemotePolicy>,
+}
+
+impl OfferFilter {
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if let (Some(min), Some(max)) = (self.min_salary, self.max_salary)
+            && min > max
+        {
+            return Err(DomainError::ValidationError(format!(
+                "min_salary ({}) is greater than max_salary ({})",
+                min, max
+            )));
+        }
+        if self.language_level.is_some() && self.language.is_none() {
+            return Err(DomainError::ValidationError(
+                "language_level requires a language".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Indique si une offre satisfait les critères, disponibilité comprise.
+    pub fn matches(&self, offer: &Offer) -> bool {
+        OfferVisibility::Available.includes(offer) && self.matches_criteria(offer)
+    }
+
+    /// Indique si une offre satisfait les critères, quelle que soit sa disponibilité.
+    /// Les bornes de salaire doivent être exprimées dans la devise de référence
+    /// (voir `in_base_currency`).
+    pub fn matches_criteria(&self, offer: &Offer) -> bool {
+        let base_salary = offer.base_salary.as_ref().map(|m| m.amount);
+        self.domain
+            .as_ref()
+            .is_none_or(|d| same_key(&offer.domain, d))
+            && self.city.as_ref().is_none_or(|c| same_key(&offer.city, c))
+            && self
+                .min_salary
+                .is_none_or(|min| base_salary.is_some_and(|s| s >= minor_units(min)))
+            && self
+                .max_salary
+                .is_none_or(|max| base_salary.is_some_and(|s| s <= minor_units(max)))
+            && self.start_after.is_none_or(|d| offer.start_date >= d)
+            && self.end_before.is_none_or(|d| offer.end_date <= d)
+            && self
+                .text
+                .as_ref()
+                .is_none_or(|t| offer.title.to_lowercase().contains(&t.to_lowercase()))
+            && self
+                .company_id
+                .is_none_or(|id| offer.company_id == Some(id))
+            && self
+                .skill
+                .as_ref()
+                .is_none_or(|skill| offer.skills.iter().any(|s| same_key(s, skill)))
+            && self.language.as_ref().is_none_or(|language| {
+                offer.languages.iter().any(|l| {
+                    same_key(&l.language, language)
+                        && self.language_level.is_none_or(|level| l.level <= level)
+                })
+            })
+            && self
+                .remote_policy
+                .is_none_or(|policy| offer.remote_policy == policy)
+    }
+
+    /// Convertit les bornes de salaire dans la devise de référence des taux.
+    pub fn in_base_currency(&self, rates: &ExchangeRates) -> Result<Self, DomainError> {
+        let Some(ref currency) = self.currency else {
+            return Ok(self.clone());
+        };
+        let convert = |bound: Option<f64>| {
+            bound
+                .map(|value| {
+                    let money = Money::from_major(value, currency, SalaryPeriod::Monthly);
+                    rates
+                        .convert(&money, rates.base())
+                        .map(|m| m.amount as f64 / 100.0)
+                })
+                .transpose()
+        };
+        Ok(Self {
+            min_salary: convert(self.min_salary)?,
+            max_salary: convert(self.max_salary)?,
+            currency: None,
+            ..self.clone()
+        })
+    }
+
+    /// Paramètres de requête équivalents, pour relayer la recherche à Erasmumu.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+        if let Some(ref domain) = self.domain {
+            query.push(("domain", domain.clone()));
+        }
+        if let Some(ref city) = self.city {
+            query.push(("city", city.clone()));
+        }
+        if let Some(min) = self.min_salary {
+            query.push(("min_salary", min.to_string()));
+        }
+        if let Some(max) = self.max_salary {
+            query.push(("max_salary", max.to_string()));
+        }
+        if let Some(ref currency) = self.currency {
+            query.push(("currency", currency.clone()));
+        }
+        if let Some(date) = self.start_after {
+            query.push(("start_after", date.to_string()));
+        }
+        if let Some(date) = self.end_before {
+            query.push(("end_before", date.to_string()));
+        }
+        if let Some(ref text) = self.text {
+            query.push(("text", text.clone()));
+        }
+        if let Some(id) = self.company_id {
+            query.push(("company_id", id.to_string()));
+        }
+        if let Some(ref skill) = self.skill {
+            query.push(("skill", skill.clone()));
+        }
+        if let Some(ref language) = self.language {
+            query.push(("language", language.clone()));
+        }
+        if let Some(level) = self.language_level {
+            query.push(("language_level", level.as_str().to_string()));
+        }
+        if let Some(policy) = self.remote_policy {
+            query.push(("remote_policy", policy.as_str().to_string()));
+        }
+        query
+    }
+}
+
+/// Valeur d'un champ avant et après la mise à jour d'une offre.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldChange {
+    #[schema(value_type = Object)]
+    pub from: serde_json::Value,
+    #[schema(value_type = Object)]
+    pub to: serde_json::Value,
+}
+
+/// Charge utile de l'événement `offer.updated` : l'offre mise à jour, à plat comme pour
+/// `offer.created`, et les champs modifiés indexés par leur nom.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OfferUpdatedEvent {
+    #[serde(flatten)]
+    pub offer: Offer,
+    pub changes: BTreeMap<String, FieldChange>,
+}
+
+impl OfferUpdatedEvent {
+    /// Compare les deux versions champ par champ ; `None` si rien n'a changé.
+    /// Le numéro de version, incrémenté par toute écriture, n'est pas un changement.
+    pub fn diff(before: &Offer, after: &Offer) -> Option<Self> {
+        let (Ok(serde_json::Value::Object(from)), Ok(serde_json::Value::Object(mut to))) =
+            (serde_json::to_value(before), serde_json::to_value(after))
+        else {
+            return None;
+        };
+        let changes: BTreeMap<String, FieldChange> = from
+            .into_iter()
+            .filter(|(field, _)| field != "version")
+            .filter_map(|(field, from)| {
+                let to = to.remove(&field).unwrap_or_default();
+                (from != to).then_some((field, FieldChange { from, to }))
+            })
+            .collect();
+        (!changes.is_empty()).then(|| Self {
+            offer: after.clone(),
+            changes,
+        })
+    }
+
+    pub fn changed(&self, field: &str) -> bool {
+        self.changes.contains_key(field)
+    }
+
+    /// Valeur textuelle d'un champ avant la mise à jour (valeur courante s'il n'a pas changé).
+    pub fn previous_str<'a>(&'a self, field: &str, current: &'a str) -> &'a str {
+        self.changes
+            .get(field)
+            .and_then(|c| c.from.as_str())
+            .unwrap_or(current)
+    }
+}
+
+/// Révision d'une offre : une mise à jour, jamais modifiée une fois enregistrée.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OfferRevision {
+    pub id: Uuid,
+    pub offer_id: Uuid,
+    /// Version de l'offre produite par la mise à jour.
+    pub version: u64,
+    /// Auteur de la mise à jour, s'il s'est fait connaître.
+    pub author: Option<String>,
+    pub changed_at: DateTime<Utc>,
+    pub changes: BTreeMap<String, FieldChange>,
+}
+
+impl OfferRevision {
+    pub fn new(
+        event: &OfferUpdatedEvent,
+        author: Option<String>,
+        changed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            offer_id: event.offer.id,
+            version: event.offer.version,
+            author,
+            changed_at,
+            changes: event.changes.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eur(amount: i64) -> Money {
+        Money::new(amount, "EUR", SalaryPeriod::Monthly)
+    }
+
+    fn offer() -> Offer {
+        Offer {
+            id: Uuid::new_v4(),
+            title: "Stage Backend Rust".to_string(),
+            link: "http://example.com".to_string(),
+            city: "Berlin".to_string(),
+            domain: "IT".to_string(),
+            salary: eur(140_000),
+            base_salary: Some(eur(140_000)),
+            start_date: NaiveDate::from_ymd_opt(2026, 9, 1).unwrap(),
+            end_date: NaiveDate::from_ymd_opt(2027, 2, 28).unwrap(),
+            available: true,
+            seats: 1,
+            reserved_seats: 0,
+            deleted_at: None,
+            application_deadline: None,
+            company_id: None,
+            description: Some("Développement d'un **service** d'ingestion.".to_string()),
+            skills: vec!["Rust".to_string(), "PostgreSQL".to_string()],
+            languages: vec![LanguageRequirement {
+                language: "Deutsch".to_string(),
+                level: CefrLevel::B1,
+            }],
+            remote_policy: RemotePolicy::Hybrid,
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_filter_combines_all_criteria() {
+        let filter = OfferFilter {
+            domain: Some("IT".to_string()),
+            city: Some("Berlin".to_string()),
+            min_salary: Some(1200.0),
+            start_after: NaiveDate::from_ymd_opt(2026, 9, 1),
+            text: Some("rust".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&offer()));
+
+        let other_city = Offer {
+            city: "Paris".to_string(),
+            ..offer()
+        };
+        assert!(!filter.matches(&other_city));
+
+        let underpaid = Offer {
+            base_salary: Some(eur(100_000)),
+            ..offer()
+        };
+        assert!(!filter.matches(&underpaid));
+    }
+
+    #[test]
+    fn test_filter_uses_canonical_keys() {
+        let filter = OfferFilter {
+            domain: Some("it".to_string()),
+            city: Some(" BERLÍN".to_string()),
+            ..Default::default()
+        };
+        assert!(filter.matches(&offer()));
+    }
+
+    #[test]
+    fn test_filter_excludes_unavailable_offers() {
+        let unavailable = Offer {
+            available: false,
+            ..offer()
+        };
+        assert!(!OfferFilter::default().matches(&unavailable));
+        assert!(OfferFilter::default().matches_criteria(&unavailable));
+        assert!(!OfferVisibility::Available.includes(&unavailable));
+        assert!(OfferVisibility::Hidden.includes(&unavailable));
+        assert!(OfferVisibility::All.includes(&unavailable));
+
+        let archived = Offer {
+            deleted_at: Some(Utc::now()),
+            ..offer()
+        };
+        assert!(!OfferFilter::default().matches(&archived));
+        assert!(!OfferVisibility::Hidden.includes(&archived));
+        assert!(OfferVisibility::Archived.includes(&archived));
+    }
+
+    #[test]
+    fn test_filter_on_skills_languages_and_remote_policy() {
+        let filter = OfferFilter {
+            skill: Some("rust".to_string()),
+            language: Some("deutsch".to_string()),
+            language_level: Some(CefrLevel::B2),
+            remote_policy: Some(RemotePolicy::Hybrid),
+            ..Default::default()
+        };
+        assert!(filter.matches(&offer()));
+
+        // Un candidat de niveau A2 n'atteint pas le B1 exigé
+        let beginner = OfferFilter {
+            language: Some("Deutsch".to_string()),
+            language_level: Some(CefrLevel::A2),
+            ..Default::default()
+        };
+        assert!(!beginner.matches(&offer()));
+
+        let remote = OfferFilter {
+            remote_policy: Some(RemotePolicy::Remote),
+            ..Default::default()
+        };
+        assert!(!remote.matches(&offer()));
+
+        let level_alone = OfferFilter {
+            language_level: Some(CefrLevel::C1),
+            ..Default::default()
+        };
+        assert!(level_alone.validate().is_err());
+        assert!(CefrLevel::A1 < CefrLevel::C2);
+    }
+
+    #[test]
+    fn test_salary_bounds_are_converted_to_base_currency() {
+        let rates = ExchangeRates::from_decimal("EUR", [("GBP".to_string(), 0.8)]).unwrap();
+        let filter = OfferFilter {
+            min_salary: Some(1200.0),
+            currency: Some("GBP".to_string()),
+            ..Default::default()
+        };
+        let converted = filter.in_base_currency(&rates).unwrap();
+        assert_eq!(converted.min_salary, Some(1500.0));
+        assert_eq!(converted.currency, None);
+        // 1 400 € par mois, soit 1 120 £ : sous la borne
+        assert!(!converted.matches(&offer()));
+
+        let unknown = OfferFilter {
+            currency: Some("XYZ".to_string()),
+            ..filter
+        };
+        assert!(unknown.in_base_currency(&rates).is_err());
+    }
+
+    #[test]
+    fn test_inverted_salary_range_is_rejected() {
+        let filter = OfferFilter {
+            min_salary: Some(2000.0),
+            max_salary: Some(1000.0),
+            ..Default::default()
+        };
+        assert!(filter.validate().is_err());
+    }
+
+    #[test]
+    fn test_offer_expires_after_its_deadline() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 8, d).unwrap();
+        let without_deadline = offer();
+        assert!(!without_deadline.is_expired(NaiveDate::from_ymd_opt(2027, 2, 28).unwrap()));
+        assert!(without_deadline.is_expired(NaiveDate::from_ymd_opt(2027, 3, 1).unwrap()));
+
+        let with_deadline = Offer {
+            application_deadline: Some(day(15)),
+            ..offer()
+        };
+        assert!(!with_deadline.is_expired(day(15)));
+        assert!(with_deadline.is_expired(day(16)));
+    }
+
+    #[test]
+    fn test_updated_event_lists_changed_fields_only() {
+        let before = offer();
+        assert!(OfferUpdatedEvent::diff(&before, &before).is_none());
+
+        let after = Offer {
+            city: "Munich".to_string(),
+            salary: eur(150_000),
+            version: before.version + 1,
+            ..before.clone()
+        };
+        let event = OfferUpdatedEvent::diff(&before, &after).unwrap();
+        assert_eq!(
+            event.changes.keys().collect::<Vec<_>>(),
+            vec!["city", "salary"]
+        );
+        assert_eq!(event.previous_str("city", &event.offer.city), "Berlin");
+        assert_eq!(event.previous_str("domain", &event.offer.domain), "IT");
+
+        // Les consommateurs d'offer.created lisent aussi offer.updated
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["city"], "Munich");
+        assert_eq!(json["changes"]["city"]["from"], "Berlin");
+    }
 }
@@ -1,13 +1,130 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use crate::domain::entities::domain::deserialize_present;
+use crate::domain::entities::file::StoredFile;
+use crate::domain::entities::internship::{Internship, InternshipStatusChange};
+use crate::domain::entities::notification::Notification;
+use crate::domain::entities::offer::LanguageRequirement;
+use crate::domain::entities::subscriber::Subscriber;
+use crate::domain::normalize::{canonical_key, same_key};
+use crate::domain::pagination::{Paginated, SortKey};
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct Student {
     pub id: Uuid,
     pub firstname: String,
     pub name: String,
     pub domain: String,
+    /// Adresse de contact, reprise par La Poste pour les notifications.
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub phone: Option<String>,
+    /// Année d'études après le baccalauréat.
+    #[serde(default)]
+    pub study_year: Option<u8>,
+    /// Langues parlées, chacune avec le niveau atteint.
+    #[serde(default)]
+    pub languages: Vec<LanguageRequirement>,
+    /// CV déposé, absent tant que l'étudiant n'en a pas envoyé.
+    #[serde(default)]
+    pub cv: Option<StoredFile>,
+    /// Incrémentée à chaque écriture ; sert d'ETag aux mises à jour concurrentes.
+    #[serde(default)]
+    pub version: u64,
+}
+
+impl Student {
+    /// Clé inédite pour un nouveau dépôt de CV : l'ancien fichier reste en place tant que
+    /// l'étudiant n'a pas été mis à jour, puis est supprimé.
+    pub fn new_cv_key(&self) -> String {
+        format!("cv/{}/{}", self.id, Uuid::new_v4())
+    }
+}
+
+impl Paginated for Student {
+    const SORT_FIELDS: &'static [&'static str] = &["name", "firstname"];
+
+    fn id(&self) -> Uuid {
+        self.id
+    }
+
+    fn sort_key(&self, field: &str) -> SortKey {
+        match field {
+            "firstname" => SortKey::Text(self.firstname.clone()),
+            _ => SortKey::Text(self.name.clone()),
+        }
+    }
+}
+
+/// Critères de recherche d'étudiants, combinés par ET ; sans critère, toute la promotion.
+/// Nom et domaine sont comparés par clé canonique (voir `normalize::canonical_key`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StudentFilter {
+    /// Début du nom ou du prénom.
+    pub name: Option<String>,
+    pub domain: Option<String>,
+    pub study_year: Option<u8>,
+}
+
+impl StudentFilter {
+    pub fn matches(&self, student: &Student) -> bool {
+        self.name.as_ref().is_none_or(|prefix| {
+            let prefix = canonical_key(prefix);
+            canonical_key(&student.name).starts_with(&prefix)
+                || canonical_key(&student.firstname).starts_with(&prefix)
+        }) && self
+            .domain
+            .as_ref()
+            .is_none_or(|d| same_key(&student.domain, d))
+            && self
+                .study_year
+                .is_none_or(|year| student.study_year == Some(year))
+    }
+}
+
+/// Données supprimées avec un étudiant par `StudentRepository::erase`.
+#[derive(Debug, Clone, Default)]
+pub struct ErasedRecords {
+    pub internships: Vec<Internship>,
+    /// Entrées de l'historique des candidatures supprimées.
+    pub status_changes: u64,
+    pub notifications: u64,
+}
+
+/// Compte rendu de l'effacement d'un étudiant (droit à l'effacement du RGPD).
+/// Il ne contient aucune donnée personnelle et peut être conservé comme preuve.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ErasureReport {
+    pub student_id: Uuid,
+    pub erased_at: DateTime<Utc>,
+    pub internships: u64,
+    pub status_changes: u64,
+    pub notifications: u64,
+    pub cv_deleted: bool,
+    /// Offres dont une place, tenue par une candidature en cours, a été libérée.
+    pub released_seats: Vec<Uuid>,
+    /// `student.deleted` a été publié : La Poste supprime alors l'abonné.
+    pub deletion_published: bool,
+    /// Relecture après effacement : plus aucune donnée de l'étudiant n'est lisible dans Polytech.
+    pub verified: bool,
+}
+
+/// Copie des données d'un étudiant (droit d'accès du RGPD), réunies depuis Polytech et La Poste.
+/// Le contenu du CV se télécharge à part ; seul son descriptif figure dans `student`.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StudentExport {
+    pub exported_at: DateTime<Utc>,
+    pub student: Student,
+    pub internships: Vec<Internship>,
+    /// Historique des statuts de toutes les candidatures.
+    pub internship_history: Vec<InternshipStatusChange>,
+    pub notifications: Vec<Notification>,
+    /// Préférences de notification ; absentes si l'étudiant n'est pas abonné à La Poste.
+    pub subscriber: Option<Subscriber>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -15,6 +132,14 @@ pub struct CreateStudentRequest {
     pub firstname: String,
     pub name: String,
     pub domain: String,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub study_year: Option<u8>,
+    #[serde(default)]
+    pub languages: Vec<LanguageRequirement>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -22,4 +147,59 @@ pub struct UpdateStudentRequest {
     pub firstname: Option<String>,
     pub name: Option<String>,
     pub domain: Option<String>,
+    /// Absent : adresse inchangée ; `null` : l'étudiant n'a plus d'adresse.
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub email: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub phone: Option<Option<String>>,
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub study_year: Option<Option<u8>>,
+    pub languages: Option<Vec<LanguageRequirement>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn student(firstname: &str, name: &str, study_year: Option<u8>) -> Student {
+        Student {
+            id: Uuid::new_v4(),
+            firstname: firstname.to_string(),
+            name: name.to_string(),
+            domain: "Santé".to_string(),
+            email: None,
+            phone: None,
+            study_year,
+            languages: vec![],
+            cv: None,
+            version: 1,
+        }
+    }
+
+    #[test]
+    fn test_student_filter_matches_name_prefix_and_year() {
+        let eloise = student("Éloïse", "Martin", Some(4));
+        let filter = StudentFilter {
+            name: Some("elo".to_string()),
+            domain: Some("sante".to_string()),
+            study_year: Some(4),
+        };
+        assert!(filter.matches(&eloise));
+        assert!(StudentFilter::default().matches(&eloise));
+
+        let by_name = StudentFilter {
+            name: Some("MAR".to_string()),
+            ..Default::default()
+        };
+        assert!(by_name.matches(&eloise));
+        assert!(by_name.matches(&student("Marc", "Dupont", None)));
+        assert!(!by_name.matches(&student("Anne", "Lemar", Some(4))));
+
+        let fifth_year = StudentFilter {
+            study_year: Some(5),
+            ..Default::default()
+        };
+        assert!(!fifth_year.matches(&eloise));
+        assert!(!fifth_year.matches(&student("Paul", "Durand", None)));
+    }
 }
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Préférences de notification d'un étudiant, tenues par La Poste.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct Subscriber {
+    #[serde(rename = "studentId")]
+    pub student_id: String,
+    pub domain: String,
+    /// Canal des alertes (`email`, `discord`…).
+    pub channel: String,
+    /// Adresse sur ce canal ; vide tant que l'étudiant n'en a pas donné.
+    pub contact: String,
+    pub enabled: bool,
+}
@@ -1,2 +1,7 @@
+pub mod concurrency;
+pub mod eligibility;
 pub mod entities;
+pub mod normalize;
+pub mod pagination;
 pub mod ports;
+pub mod validation;
@@ -0,0 +1,39 @@
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+/// Clé canonique d'une ville ou d'un domaine, partagée par tous les services :
+/// accents supprimés, casse repliée, espaces superflus retirés.
+/// La valeur d'origine reste la valeur affichée.
+pub fn canonical_key(value: &str) -> String {
+    let stripped: String = value.nfd().filter(|c| !is_combining_mark(*c)).collect();
+    stripped
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Compare deux valeurs selon leur clé canonique.
+pub fn same_key(a: &str, b: &str) -> bool {
+    canonical_key(a) == canonical_key(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_case_accents_and_whitespace_are_ignored() {
+        assert_eq!(canonical_key("  Île-de-France "), "ile-de-france");
+        assert_eq!(canonical_key("ILE-DE-FRANCE"), "ile-de-france");
+        assert_eq!(canonical_key("Saint   Étienne"), "saint etienne");
+        assert!(same_key("paris", "Paris"));
+        assert!(same_key("Sciences de la Vie", "sciences de la vie"));
+    }
+
+    #[test]
+    fn test_distinct_values_keep_distinct_keys() {
+        assert!(!same_key("Paris", "Parisot"));
+        assert!(!same_key("IT", "Life Science"));
+    }
+}
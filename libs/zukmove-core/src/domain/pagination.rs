@@ -0,0 +1,340 @@
+use std::cmp::Ordering;
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::ports::DomainError;
+
+/// Valeur de la clé de tri d'un élément.
+/// `Null` (valeur absente) précède toute autre valeur, comme dans MongoDB.
+#[derive(Debug, Clone, PartialEq, PartialOrd, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SortKey {
+    Null,
+    Number(f64),
+    Text(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Asc,
+    Desc,
+}
+
+/// Tri d'une liste : `champ` (croissant) ou `-champ` (décroissant).
+/// L'identifiant départage les éléments de même clé.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Sort {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+impl Sort {
+    pub fn parse(spec: &str, allowed: &[&str]) -> Result<Self, DomainError> {
+        let (field, direction) = match spec.strip_prefix('-') {
+            Some(field) => (field, SortDirection::Desc),
+            None => (spec, SortDirection::Asc),
+        };
+        if !allowed.contains(&field) {
+            return Err(DomainError::ValidationError(format!(
+                "Cannot sort by '{}', expected one of: {}",
+                field,
+                allowed.join(", ")
+            )));
+        }
+        Ok(Self {
+            field: field.to_string(),
+            direction,
+        })
+    }
+}
+
+impl std::fmt::Display for Sort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.direction {
+            SortDirection::Asc => write!(f, "{}", self.field),
+            SortDirection::Desc => write!(f, "-{}", self.field),
+        }
+    }
+}
+
+/// Position du dernier élément d'une page, transmise au client sous forme opaque.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    pub sort: String,
+    pub key: SortKey,
+    pub id: Uuid,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor is always serializable");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, DomainError> {
+        URL_SAFE_NO_PAD
+            .decode(encoded)
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(&bytes).ok())
+            .ok_or_else(|| DomainError::ValidationError("Invalid cursor".to_string()))
+    }
+}
+
+/// Élément pouvant être listé page par page.
+pub trait Paginated {
+    /// Champs de tri acceptés ; le premier est le tri par défaut.
+    const SORT_FIELDS: &'static [&'static str];
+
+    fn id(&self) -> Uuid;
+    fn sort_key(&self, field: &str) -> SortKey;
+}
+
+/// Demande de page : taille, position de départ et tri.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRequest {
+    pub limit: u32,
+    pub cursor: Option<Cursor>,
+    pub sort: Sort,
+}
+
+impl PageRequest {
+    pub const DEFAULT_LIMIT: u32 = 20;
+    pub const MAX_LIMIT: u32 = 100;
+
+    /// Première page, tri par défaut de `T`.
+    pub fn first<T: Paginated>(limit: u32) -> Self {
+        Self {
+            limit,
+            cursor: None,
+            sort: Sort {
+                field: T::SORT_FIELDS[0].to_string(),
+                direction: SortDirection::Asc,
+            },
+        }
+    }
+
+    /// Construit une demande à partir des paramètres `limit`, `cursor` et `sort` d'une requête.
+    pub fn parse<T: Paginated>(
+        limit: Option<u32>,
+        cursor: Option<&str>,
+        sort: Option<&str>,
+    ) -> Result<Self, DomainError> {
+        let limit = limit.unwrap_or(Self::DEFAULT_LIMIT);
+        if limit == 0 || limit > Self::MAX_LIMIT {
+            return Err(DomainError::ValidationError(format!(
+                "limit must be between 1 and {}",
+                Self::MAX_LIMIT
+            )));
+        }
+        let sort = Sort::parse(sort.unwrap_or(T::SORT_FIELDS[0]), T::SORT_FIELDS)?;
+        let cursor = cursor.map(Cursor::decode).transpose()?;
+        if let Some(ref cursor) = cursor
+            && cursor.sort != sort.to_string()
+        {
+            return Err(DomainError::ValidationError(
+                "cursor was issued for a different sort".to_string(),
+            ));
+        }
+        Ok(Self {
+            limit,
+            cursor,
+            sort,
+        })
+    }
+
+    /// Paramètres de requête équivalents, pour relayer la demande à un autre service.
+    pub fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = vec![
+            ("limit", self.limit.to_string()),
+            ("sort", self.sort.to_string()),
+        ];
+        if let Some(ref cursor) = self.cursor {
+            query.push(("cursor", cursor.encode()));
+        }
+        query
+    }
+
+    /// Compare deux éléments selon le tri demandé.
+    pub fn compare<T: Paginated>(&self, a: &T, b: &T) -> Ordering {
+        let ordering = compare_keys(
+            &a.sort_key(&self.sort.field),
+            a.id(),
+            &b.sort_key(&self.sort.field),
+            b.id(),
+        );
+        match self.sort.direction {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+
+    /// Indique si un élément se trouve après le curseur dans l'ordre demandé.
+    pub fn is_after_cursor<T: Paginated>(&self, item: &T) -> bool {
+        let Some(ref cursor) = self.cursor else {
+            return true;
+        };
+        let ordering = compare_keys(
+            &item.sort_key(&self.sort.field),
+            item.id(),
+            &cursor.key,
+            cursor.id,
+        );
+        match self.sort.direction {
+            SortDirection::Asc => ordering == Ordering::Greater,
+            SortDirection::Desc => ordering == Ordering::Less,
+        }
+    }
+}
+
+fn compare_keys(a: &SortKey, a_id: Uuid, b: &SortKey, b_id: Uuid) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or(Ordering::Equal)
+        .then_with(|| a_id.cmp(&b_id))
+}
+
+/// Page de résultats ; `next_cursor` est absent sur la dernière page.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T: Paginated> Page<T> {
+    /// Construit une page à partir d'au plus `limit + 1` éléments déjà triés et filtrés :
+    /// la présence d'un élément supplémentaire signale une page suivante.
+    pub fn from_overfetch(mut items: Vec<T>, request: &PageRequest) -> Self {
+        let has_more = items.len() > request.limit as usize;
+        items.truncate(request.limit as usize);
+        let next_cursor = if has_more {
+            items.last().map(|last| {
+                Cursor {
+                    sort: request.sort.to_string(),
+                    key: last.sort_key(&request.sort.field),
+                    id: last.id(),
+                }
+                .encode()
+            })
+        } else {
+            None
+        };
+        Self { items, next_cursor }
+    }
+
+    /// Pagine une collection complète en mémoire.
+    pub fn paginate(items: impl IntoIterator<Item = T>, request: &PageRequest) -> Self {
+        let mut items: Vec<T> = items
+            .into_iter()
+            .filter(|item| request.is_after_cursor(item))
+            .collect();
+        items.sort_by(|a, b| request.compare(a, b));
+        items.truncate(request.limit as usize + 1);
+        Self::from_overfetch(items, request)
+    }
+}
+
+impl<T> Page<T> {
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            next_cursor: self.next_cursor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Item {
+        id: Uuid,
+        rank: f64,
+    }
+
+    impl Paginated for Item {
+        const SORT_FIELDS: &'static [&'static str] = &["rank"];
+
+        fn id(&self) -> Uuid {
+            self.id
+        }
+
+        fn sort_key(&self, _field: &str) -> SortKey {
+            SortKey::Number(self.rank)
+        }
+    }
+
+    fn items() -> Vec<Item> {
+        (0..5)
+            .map(|i| Item {
+                id: Uuid::new_v4(),
+                rank: f64::from(i % 3),
+            })
+            .collect()
+    }
+
+    fn collect_all(items: &[Item], sort: &str) -> Vec<Item> {
+        let mut seen = Vec::new();
+        let mut cursor: Option<String> = None;
+        loop {
+            let request =
+                PageRequest::parse::<Item>(Some(2), cursor.as_deref(), Some(sort)).unwrap();
+            let page = Page::paginate(items.iter().cloned(), &request);
+            assert!(page.items.len() <= 2);
+            seen.extend(page.items);
+            match page.next_cursor {
+                Some(next) => cursor = Some(next),
+                None => return seen,
+            }
+        }
+    }
+
+    #[test]
+    fn test_pages_cover_every_item_once_in_order() {
+        let items = items();
+        let asc = collect_all(&items, "rank");
+        assert_eq!(asc.len(), items.len());
+        assert!(asc.windows(2).all(|w| w[0].rank <= w[1].rank));
+
+        let desc = collect_all(&items, "-rank");
+        assert_eq!(desc.len(), items.len());
+        assert!(desc.windows(2).all(|w| w[0].rank >= w[1].rank));
+    }
+
+    #[test]
+    fn test_cursor_round_trip() {
+        let cursor = Cursor {
+            sort: "-title".to_string(),
+            key: SortKey::Text("Stage".to_string()),
+            id: Uuid::new_v4(),
+        };
+        assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert!(Cursor::decode("not a cursor").is_err());
+
+        let missing = Cursor {
+            sort: "salary".to_string(),
+            key: SortKey::Null,
+            id: Uuid::new_v4(),
+        };
+        assert_eq!(Cursor::decode(&missing.encode()).unwrap(), missing);
+        assert!(SortKey::Null < SortKey::Number(0.0));
+    }
+
+    #[test]
+    fn test_invalid_page_requests_are_rejected() {
+        assert!(PageRequest::parse::<Item>(Some(0), None, None).is_err());
+        assert!(PageRequest::parse::<Item>(Some(1000), None, None).is_err());
+        assert!(PageRequest::parse::<Item>(None, None, Some("unknown")).is_err());
+
+        let cursor = Cursor {
+            sort: "rank".to_string(),
+            key: SortKey::Number(1.0),
+            id: Uuid::new_v4(),
+        }
+        .encode();
+        assert!(PageRequest::parse::<Item>(None, Some(&cursor), Some("-rank")).is_err());
+    }
+}
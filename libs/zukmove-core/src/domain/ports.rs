@@ -1,12 +1,19 @@
 use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
 use uuid::Uuid;
 
 use super::entities::city_score::CityScore;
-use super::entities::internship::Internship;
+use super::entities::company::Company;
+use super::entities::domain::{Domain, DomainTaxonomy};
+use super::entities::internship::{Internship, InternshipFilter, InternshipStatusChange};
+use super::entities::money::ExchangeRates;
 use super::entities::news::News;
 use super::entities::notification::Notification;
-use super::entities::offer::Offer;
-use super::entities::student::Student;
+use super::entities::offer::{Offer, OfferFilter, OfferRevision, OfferVisibility};
+use super::entities::student::{ErasedRecords, Student, StudentFilter};
+use super::entities::subscriber::Subscriber;
+use super::pagination::{Page, PageRequest};
+use super::validation::FieldError;
 
 /// Domain error type
 #[derive(Debug, thiserror::Error)]
@@ -17,6 +24,17 @@ pub enum DomainError {
     #[error("Validation error: {0}")]
     ValidationError(String),
 
+    /// Champs refusés par `validation::Validate`, tous listés.
+    #[error("Invalid fields: {}", .0.iter().map(|e| e.field.as_str()).collect::<Vec<_>>().join(", "))]
+    InvalidFields(Vec<FieldError>),
+
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
+    /// La ressource a changé depuis sa lecture : la mise à jour aurait écrasé une autre.
+    #[error("Precondition failed: {0}")]
+    PreconditionFailed(String),
+
     #[error("Infrastructure error: {0}")]
     InfrastructureError(String),
 }
@@ -27,30 +45,149 @@ pub enum DomainError {
 pub trait StudentRepository: Send + Sync {
     async fn save(&self, student: &Student) -> Result<Student, DomainError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Student, DomainError>;
-    async fn find_by_domain(&self, domain: &str) -> Result<Vec<Student>, DomainError>;
+    async fn search(
+        &self,
+        filter: &StudentFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Student>, DomainError>;
+    /// Écrit l'étudiant si sa version est toujours `student.version`, puis l'incrémente.
+    /// Retourne `PreconditionFailed` si l'étudiant a été modifié entre-temps.
     async fn update(&self, student: &Student) -> Result<Student, DomainError>;
+    /// Supprime l'étudiant avec ses candidatures, leur historique et ses notifications,
+    /// en une seule transaction. Retourne `NotFound` si l'étudiant n'existe pas.
+    async fn erase(&self, id: Uuid) -> Result<ErasedRecords, DomainError>;
+}
+
+// ─── Domain Repository (Port) ───
+
+#[async_trait]
+pub trait DomainRepository: Send + Sync {
+    async fn save(&self, domain: &Domain) -> Result<Domain, DomainError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Domain, DomainError>;
+    async fn find_all(&self) -> Result<Vec<Domain>, DomainError>;
+    async fn update(&self, domain: &Domain) -> Result<Domain, DomainError>;
     async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
 }
 
+// ─── Company Repository (Port) ───
+
+#[async_trait]
+pub trait CompanyRepository: Send + Sync {
+    async fn save(&self, company: &Company) -> Result<Company, DomainError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Company, DomainError>;
+    /// Toutes les entreprises, triées par nom.
+    async fn find_all(&self) -> Result<Vec<Company>, DomainError>;
+    async fn update(&self, company: &Company) -> Result<Company, DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
+
+// ─── File Storage (Port) ───
+
+#[async_trait]
+pub trait FileStorage: Send + Sync {
+    /// Enregistre le contenu sous `key`, en remplaçant le fichier existant.
+    async fn put(&self, key: &str, content: &[u8]) -> Result<(), DomainError>;
+    /// Retourne `NotFound` si aucun fichier n'est enregistré sous `key`.
+    async fn get(&self, key: &str) -> Result<Vec<u8>, DomainError>;
+    /// Supprime le fichier ; sans effet s'il n'existe pas.
+    async fn delete(&self, key: &str) -> Result<(), DomainError>;
+}
+
 // ─── Offer Repository (Port) ───
 
 #[async_trait]
 pub trait OfferRepository: Send + Sync {
     async fn save(&self, offer: &Offer) -> Result<Offer, DomainError>;
+    /// Enregistre plusieurs nouvelles offres en une seule écriture.
+    async fn save_many(&self, offers: &[Offer]) -> Result<(), DomainError>;
+    /// Retourne `NotFound` pour une offre indisponible ou archivée.
     async fn find_by_id(&self, id: Uuid) -> Result<Offer, DomainError>;
-    async fn find_by_domain(&self, domain: &str) -> Result<Vec<Offer>, DomainError>;
-    async fn find_by_city(&self, city: &str) -> Result<Vec<Offer>, DomainError>;
-    async fn find_all(&self) -> Result<Vec<Offer>, DomainError>;
+    /// Lecture d'administration : retourne aussi une offre indisponible ou archivée.
+    async fn find_by_id_including_unavailable(&self, id: Uuid) -> Result<Offer, DomainError>;
+    /// Recherche paginée des offres de la visibilité demandée satisfaisant tous les critères du filtre.
+    async fn search(
+        &self,
+        filter: &OfferFilter,
+        visibility: OfferVisibility,
+        page: &PageRequest,
+    ) -> Result<Page<Offer>, DomainError>;
+    /// Recherche plein texte dans le titre des offres disponibles,
+    /// classée par pertinence décroissante.
+    async fn search_text(&self, query: &str, limit: u32) -> Result<Vec<Offer>, DomainError>;
+    /// Écrit l'offre si sa version est toujours `offer.version`, puis l'incrémente.
+    /// Retourne `PreconditionFailed` si l'offre a été modifiée entre-temps.
+    /// Les autres écritures (archivage, réservations, expiration) incrémentent aussi la version.
     async fn update(&self, offer: &Offer) -> Result<Offer, DomainError>;
-    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Suppression logique : l'offre est archivée à `deleted_at` et sort des recherches publiques.
+    /// Retourne `NotFound` si l'offre n'existe pas ou est déjà archivée.
+    async fn archive(&self, id: Uuid, deleted_at: DateTime<Utc>) -> Result<Offer, DomainError>;
+    /// Restaure une offre archivée. Retourne `Conflict` si elle n'est pas archivée.
+    async fn restore(&self, id: Uuid) -> Result<Offer, DomainError>;
+    /// Réserve atomiquement une place pour une candidature acceptée, même sur une offre masquée
+    /// ou expirée ; l'offre devient indisponible lorsqu'elle est complète.
+    /// Retourne `Conflict` s'il ne reste aucune place ou si l'offre est archivée.
+    async fn reserve_seat(&self, id: Uuid) -> Result<Offer, DomainError>;
+    /// Libère une place réservée ; une offre complète redevient disponible.
+    async fn release_seat(&self, id: Uuid) -> Result<Offer, DomainError>;
+    /// Rend indisponibles les offres disponibles dont la date limite est dépassée à `today`
+    /// (voir `Offer::deadline`) et retourne celles qui viennent d'expirer.
+    async fn expire_due(&self, today: NaiveDate) -> Result<Vec<Offer>, DomainError>;
+}
+
+// ─── Offer Revision Repository (Port) ───
+
+#[async_trait]
+pub trait OfferRevisionRepository: Send + Sync {
+    /// Ajoute une révision ; les révisions enregistrées ne sont jamais modifiées.
+    async fn save(&self, revision: &OfferRevision) -> Result<OfferRevision, DomainError>;
+    /// Révisions d'une offre, de la plus ancienne à la plus récente.
+    async fn find_by_offer(&self, offer_id: Uuid) -> Result<Vec<OfferRevision>, DomainError>;
 }
 
 // ─── Internship Repository (Port) ───
 
 #[async_trait]
 pub trait InternshipRepository: Send + Sync {
+    /// Enregistre une nouvelle candidature ainsi que l'entrée initiale de son historique.
+    /// Retourne `Conflict` si l'étudiant a déjà une candidature à cette offre
+    /// qui n'a été ni refusée ni retirée.
     async fn save(&self, internship: &Internship) -> Result<Internship, DomainError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Internship, DomainError>;
+    /// Page des candidatures d'un étudiant, triées par identifiant.
+    async fn find_by_student(
+        &self,
+        student_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError>;
+    /// Candidature de l'étudiant à cette offre qui n'a été ni refusée ni retirée.
+    async fn find_by_student_and_offer(
+        &self,
+        student_id: Uuid,
+        offer_id: Uuid,
+    ) -> Result<Option<Internship>, DomainError>;
+    /// Page des candidatures à une offre, triées par identifiant.
+    async fn find_by_offer(
+        &self,
+        offer_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError>;
+    /// Recherche paginée des candidatures satisfaisant tous les critères du filtre,
+    /// triées par identifiant.
+    async fn search(
+        &self,
+        filter: &InternshipFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Internship>, DomainError>;
+    /// Applique un changement de statut et l'ajoute à l'historique.
+    /// Échoue si le statut courant ne correspond plus à `change.from`.
+    async fn update_status(
+        &self,
+        change: &InternshipStatusChange,
+    ) -> Result<Internship, DomainError>;
+    async fn find_history(
+        &self,
+        internship_id: Uuid,
+    ) -> Result<Vec<InternshipStatusChange>, DomainError>;
 }
 
 // ─── Offer Client (Port sortant pour communication inter-services) ───
@@ -60,9 +197,29 @@ pub trait OfferClient: Send + Sync {
     async fn get_offer_by_id(&self, id: Uuid) -> Result<Offer, DomainError>;
     async fn search_offers(
         &self,
-        domain: Option<String>,
-        city: Option<String>,
-    ) -> Result<Vec<Offer>, DomainError>;
+        filter: &OfferFilter,
+        page: &PageRequest,
+    ) -> Result<Page<Offer>, DomainError>;
+    async fn reserve_seat(&self, id: Uuid) -> Result<Offer, DomainError>;
+    async fn release_seat(&self, id: Uuid) -> Result<Offer, DomainError>;
+    /// Entreprise du référentiel tenu par Erasmumu.
+    async fn get_company(&self, id: Uuid) -> Result<Company, DomainError>;
+}
+
+// ─── Domain Client (Port sortant pour communication inter-services) ───
+
+#[async_trait]
+pub trait DomainClient: Send + Sync {
+    /// Catalogue des domaines tenu par Erasmumu.
+    async fn get_taxonomy(&self) -> Result<DomainTaxonomy, DomainError>;
+}
+
+// ─── Subscriber Client (Port sortant pour communication avec La Poste) ───
+
+#[async_trait]
+pub trait SubscriberClient: Send + Sync {
+    /// Préférences de notification de l'étudiant ; `None` s'il n'est pas abonné.
+    async fn get_subscriber(&self, student_id: Uuid) -> Result<Option<Subscriber>, DomainError>;
 }
 
 // ─── Intelligence Client (Port sortant pour communication avec MI8) ───
@@ -101,6 +258,29 @@ pub trait CityScoreRepository: Send + Sync {
 #[async_trait]
 pub trait NotificationRepository: Send + Sync {
     async fn save(&self, notification: &Notification) -> Result<Notification, DomainError>;
-    async fn find_by_student_id(&self, student_id: Uuid) -> Result<Vec<Notification>, DomainError>;
+    async fn find_by_student_id(
+        &self,
+        student_id: Uuid,
+        page: &PageRequest,
+    ) -> Result<Page<Notification>, DomainError>;
     async fn mark_as_read(&self, id: Uuid) -> Result<(), DomainError>;
 }
+
+// ─── Clock (Port) ───
+
+/// Source de l'heure courante, injectée pour tester les traitements qui dépendent de la date.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+
+    fn today(&self) -> NaiveDate {
+        self.now().date_naive()
+    }
+}
+
+// ─── Exchange Rate Provider (Port) ───
+
+#[async_trait]
+pub trait ExchangeRateProvider: Send + Sync {
+    /// Taux en vigueur, exprimés par rapport à la devise de référence.
+    async fn rates(&self) -> Result<ExchangeRates, DomainError>;
+}
@@ -0,0 +1,599 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::entities::company::{CreateCompanyRequest, UpdateCompanyRequest};
+use super::entities::money::Money;
+use super::entities::offer::{CreateOfferRequest, LanguageRequirement, Offer, UpdateOfferRequest};
+use super::entities::student::{CreateStudentRequest, UpdateStudentRequest};
+use super::normalize::same_key;
+use super::ports::DomainError;
+
+/// Longueur maximale des noms et prénoms (colonnes `VARCHAR(255)`).
+const MAX_NAME_LENGTH: usize = 255;
+/// Longueur maximale d'un domaine (colonne `VARCHAR(100)`).
+const MAX_DOMAIN_LENGTH: usize = 100;
+const MAX_TITLE_LENGTH: usize = 200;
+const MAX_DESCRIPTION_LENGTH: usize = 10_000;
+/// Longueur maximale d'une compétence ou d'une langue.
+const MAX_LABEL_LENGTH: usize = 100;
+/// Longueur maximale d'une adresse électronique (RFC 5321).
+const MAX_EMAIL_LENGTH: usize = 254;
+/// Années d'études acceptées, du bac+1 au doctorat.
+const STUDY_YEARS: std::ops::RangeInclusive<u8> = 1..=8;
+
+/// Champ refusé d'une requête, avec la raison du refus.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+/// Corps des réponses 422 : toutes les erreurs de la requête.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ValidationFailure {
+    pub error: String,
+    pub fields: Vec<FieldError>,
+}
+
+impl ValidationFailure {
+    pub fn new(fields: Vec<FieldError>) -> Self {
+        Self {
+            error: "Validation failed".to_string(),
+            fields,
+        }
+    }
+}
+
+/// Requête dont les champs sont vérifiés avant d'atteindre le stockage.
+pub trait Validate {
+    /// Retourne `DomainError::InvalidFields` avec toutes les erreurs, pas seulement la première.
+    fn validate(&self) -> Result<(), DomainError>;
+}
+
+/// Accumule les erreurs de tous les champs d'une requête.
+#[derive(Debug, Default)]
+pub struct Validator {
+    errors: Vec<FieldError>,
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn check(&mut self, ok: bool, field: &str, message: &str) -> &mut Self {
+        if !ok {
+            self.errors.push(FieldError {
+                field: field.to_string(),
+                message: message.to_string(),
+            });
+        }
+        self
+    }
+
+    /// Texte obligatoire : non vide une fois les espaces retirés, et de longueur bornée.
+    pub fn text(&mut self, field: &str, value: &str, max_length: usize) -> &mut Self {
+        if value.trim().is_empty() {
+            return self.check(false, field, "must not be empty");
+        }
+        self.check(
+            value.chars().count() <= max_length,
+            field,
+            &format!("must be at most {} characters long", max_length),
+        )
+    }
+
+    pub fn url(&mut self, field: &str, value: &str) -> &mut Self {
+        self.check(is_http_url(value), field, "must be an http(s) URL")
+    }
+
+    pub fn email(&mut self, field: &str, value: &str) -> &mut Self {
+        self.check(
+            is_email(value) && value.len() <= MAX_EMAIL_LENGTH,
+            field,
+            "must be an email address",
+        )
+    }
+
+    pub fn phone(&mut self, field: &str, value: &str) -> &mut Self {
+        self.check(
+            is_phone_number(value),
+            field,
+            "must be a phone number (digits, spaces, dots, dashes, optional leading +)",
+        )
+    }
+
+    /// Langues non vides, chacune citée une seule fois.
+    pub fn languages(&mut self, field: &str, languages: &[LanguageRequirement]) -> &mut Self {
+        for (i, requirement) in languages.iter().enumerate() {
+            self.text(field, &requirement.language, MAX_LABEL_LENGTH);
+            self.check(
+                !languages[..i]
+                    .iter()
+                    .any(|l| same_key(&l.language, &requirement.language)),
+                field,
+                "must not list a language twice",
+            );
+        }
+        self
+    }
+
+    pub fn finish(&mut self) -> Result<(), DomainError> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::InvalidFields(std::mem::take(&mut self.errors)))
+        }
+    }
+}
+
+/// URL absolue `http` ou `https` avec un hôte et sans espace.
+fn is_http_url(value: &str) -> bool {
+    let Some(rest) = value
+        .strip_prefix("https://")
+        .or_else(|| value.strip_prefix("http://"))
+    else {
+        return false;
+    };
+    let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    !host.is_empty() && !host.starts_with(':') && !value.chars().any(char::is_whitespace)
+}
+
+/// Adresse `local@domaine.tld`, sans espace ; la délivrabilité n'est pas vérifiée.
+fn is_email(value: &str) -> bool {
+    let Some((local, domain)) = value.split_once('@') else {
+        return false;
+    };
+    !local.is_empty()
+        && !domain.contains('@')
+        && domain.split('.').count() >= 2
+        && domain.split('.').all(|label| !label.is_empty())
+        && !value.chars().any(char::is_whitespace)
+}
+
+/// Numéro de 6 à 15 chiffres (E.164), séparés par des espaces, points ou tirets.
+fn is_phone_number(value: &str) -> bool {
+    let digits = value.strip_prefix('+').unwrap_or(value);
+    let count = digits.chars().filter(char::is_ascii_digit).count();
+    digits
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, ' ' | '.' | '-'))
+        && (6..=15).contains(&count)
+}
+
+/// Code de devise ISO 4217 : trois lettres majuscules.
+fn is_currency_code(value: &str) -> bool {
+    value.len() == 3 && value.chars().all(|c| c.is_ascii_uppercase())
+}
+
+/// Champs d'une offre, tous facultatifs pour servir aussi aux mises à jour partielles.
+#[derive(Default)]
+struct OfferFields<'a> {
+    title: Option<&'a str>,
+    link: Option<&'a str>,
+    city: Option<&'a str>,
+    domain: Option<&'a str>,
+    salary: Option<&'a Money>,
+    seats: Option<u32>,
+    start_date: Option<NaiveDate>,
+    end_date: Option<NaiveDate>,
+    application_deadline: Option<NaiveDate>,
+    description: Option<&'a str>,
+    skills: Option<&'a [String]>,
+    languages: Option<&'a [LanguageRequirement]>,
+}
+
+impl OfferFields<'_> {
+    fn validate(&self) -> Result<(), DomainError> {
+        let mut v = Validator::new();
+        if let Some(title) = self.title {
+            v.text("title", title, MAX_TITLE_LENGTH);
+        }
+        if let Some(link) = self.link {
+            v.url("link", link);
+        }
+        if let Some(city) = self.city {
+            v.text("city", city, MAX_NAME_LENGTH);
+        }
+        if let Some(domain) = self.domain {
+            v.text("domain", domain, MAX_DOMAIN_LENGTH);
+        }
+        if let Some(salary) = self.salary {
+            v.check(salary.amount >= 0, "salary", "must not be negative");
+            v.check(
+                is_currency_code(&salary.currency),
+                "salary.currency",
+                "must be an ISO 4217 code",
+            );
+        }
+        if let Some(seats) = self.seats {
+            v.check(seats > 0, "seats", "must be at least 1");
+        }
+        if let (Some(start), Some(end)) = (self.start_date, self.end_date) {
+            v.check(end > start, "end_date", "must be after start_date");
+        }
+        if let (Some(deadline), Some(end)) = (self.application_deadline, self.end_date) {
+            v.check(
+                deadline <= end,
+                "application_deadline",
+                "must not be after end_date",
+            );
+        }
+        if let Some(description) = self.description {
+            v.text("description", description, MAX_DESCRIPTION_LENGTH);
+        }
+        for skill in self.skills.unwrap_or_default() {
+            v.text("skills", skill, MAX_LABEL_LENGTH);
+        }
+        if let Some(languages) = self.languages {
+            v.languages("languages", languages);
+        }
+        v.finish()
+    }
+}
+
+impl Validate for CreateOfferRequest {
+    fn validate(&self) -> Result<(), DomainError> {
+        OfferFields {
+            title: Some(&self.title),
+            link: Some(&self.link),
+            city: Some(&self.city),
+            domain: Some(&self.domain),
+            salary: Some(&self.salary),
+            seats: Some(self.seats),
+            start_date: Some(self.start_date),
+            end_date: Some(self.end_date),
+            application_deadline: self.application_deadline,
+            description: self.description.as_deref(),
+            skills: Some(&self.skills),
+            languages: Some(&self.languages),
+        }
+        .validate()
+    }
+}
+
+impl Validate for UpdateOfferRequest {
+    /// Seuls les champs fournis sont vérifiés ; voir `validate_against` pour les dates.
+    fn validate(&self) -> Result<(), DomainError> {
+        self.fields().validate()
+    }
+}
+
+impl UpdateOfferRequest {
+    fn fields(&self) -> OfferFields<'_> {
+        OfferFields {
+            title: self.title.as_deref(),
+            link: self.link.as_deref(),
+            city: self.city.as_deref(),
+            domain: self.domain.as_deref(),
+            salary: self.salary.as_ref(),
+            seats: self.seats,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            application_deadline: self.application_deadline.flatten(),
+            description: self.description.as_ref().and_then(|d| d.as_deref()),
+            skills: self.skills.as_deref(),
+            languages: self.languages.as_deref(),
+        }
+    }
+
+    /// Vérifie les champs fournis ; l'ordre des dates est contrôlé sur les valeurs résultant
+    /// de la mise à jour de `existing`.
+    pub fn validate_against(&self, existing: &Offer) -> Result<(), DomainError> {
+        OfferFields {
+            start_date: self.start_date.or(Some(existing.start_date)),
+            end_date: self.end_date.or(Some(existing.end_date)),
+            application_deadline: self
+                .application_deadline
+                .unwrap_or(existing.application_deadline),
+            ..self.fields()
+        }
+        .validate()
+    }
+}
+
+/// Champs de profil d'un étudiant, tous facultatifs pour servir aussi aux mises à jour.
+#[derive(Default)]
+struct StudentFields<'a> {
+    firstname: Option<&'a str>,
+    name: Option<&'a str>,
+    domain: Option<&'a str>,
+    email: Option<&'a str>,
+    phone: Option<&'a str>,
+    study_year: Option<u8>,
+    languages: Option<&'a [LanguageRequirement]>,
+}
+
+impl StudentFields<'_> {
+    fn validate(&self) -> Result<(), DomainError> {
+        let mut v = Validator::new();
+        if let Some(firstname) = self.firstname {
+            v.text("firstname", firstname, MAX_NAME_LENGTH);
+        }
+        if let Some(name) = self.name {
+            v.text("name", name, MAX_NAME_LENGTH);
+        }
+        if let Some(domain) = self.domain {
+            v.text("domain", domain, MAX_DOMAIN_LENGTH);
+        }
+        if let Some(email) = self.email {
+            v.email("email", email);
+        }
+        if let Some(phone) = self.phone {
+            v.phone("phone", phone);
+        }
+        if let Some(year) = self.study_year {
+            v.check(
+                STUDY_YEARS.contains(&year),
+                "study_year",
+                &format!(
+                    "must be between {} and {}",
+                    STUDY_YEARS.start(),
+                    STUDY_YEARS.end()
+                ),
+            );
+        }
+        if let Some(languages) = self.languages {
+            v.languages("languages", languages);
+        }
+        v.finish()
+    }
+}
+
+impl Validate for CreateStudentRequest {
+    fn validate(&self) -> Result<(), DomainError> {
+        StudentFields {
+            firstname: Some(&self.firstname),
+            name: Some(&self.name),
+            domain: Some(&self.domain),
+            email: self.email.as_deref(),
+            phone: self.phone.as_deref(),
+            study_year: self.study_year,
+            languages: Some(&self.languages),
+        }
+        .validate()
+    }
+}
+
+impl Validate for UpdateStudentRequest {
+    fn validate(&self) -> Result<(), DomainError> {
+        StudentFields {
+            firstname: self.firstname.as_deref(),
+            name: self.name.as_deref(),
+            domain: self.domain.as_deref(),
+            email: self.email.as_ref().and_then(|e| e.as_deref()),
+            phone: self.phone.as_ref().and_then(|p| p.as_deref()),
+            study_year: self.study_year.flatten(),
+            languages: self.languages.as_deref(),
+        }
+        .validate()
+    }
+}
+
+impl Validate for CreateCompanyRequest {
+    fn validate(&self) -> Result<(), DomainError> {
+        let mut v = Validator::new();
+        v.text("name", &self.name, MAX_NAME_LENGTH)
+            .text("country", &self.country, MAX_NAME_LENGTH)
+            .text("city", &self.city, MAX_NAME_LENGTH);
+        if let Some(ref website) = self.website {
+            v.url("website", website);
+        }
+        if let Some(ref contact) = self.contact {
+            v.text("contact", contact, MAX_NAME_LENGTH);
+        }
+        v.finish()
+    }
+}
+
+impl Validate for UpdateCompanyRequest {
+    fn validate(&self) -> Result<(), DomainError> {
+        let mut v = Validator::new();
+        for (field, value) in [
+            ("name", &self.name),
+            ("country", &self.country),
+            ("city", &self.city),
+            ("contact", &self.contact),
+        ] {
+            if let Some(value) = value {
+                v.text(field, value, MAX_NAME_LENGTH);
+            }
+        }
+        if let Some(ref website) = self.website {
+            v.url("website", website);
+        }
+        v.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::entities::money::SalaryPeriod;
+    use crate::domain::entities::offer::{CefrLevel, RemotePolicy};
+
+    fn date(value: &str) -> NaiveDate {
+        value.parse().unwrap()
+    }
+
+    fn request() -> CreateOfferRequest {
+        CreateOfferRequest {
+            title: "Stage Backend Rust".to_string(),
+            link: "https://example.com/offres/42".to_string(),
+            city: "Berlin".to_string(),
+            domain: "IT".to_string(),
+            salary: Money::new(140_000, "EUR", SalaryPeriod::Monthly),
+            start_date: date("2026-09-01"),
+            end_date: date("2027-02-28"),
+            available: true,
+            seats: 1,
+            application_deadline: None,
+            company_id: None,
+            description: None,
+            skills: vec![],
+            languages: vec![],
+            remote_policy: RemotePolicy::OnSite,
+        }
+    }
+
+    fn failing_fields(result: Result<(), DomainError>) -> Vec<String> {
+        match result {
+            Err(DomainError::InvalidFields(errors)) => {
+                errors.into_iter().map(|e| e.field).collect()
+            }
+            other => panic!("expected invalid fields, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_every_failing_field_is_reported() {
+        assert!(request().validate().is_ok());
+
+        let invalid = CreateOfferRequest {
+            title: "  ".to_string(),
+            link: "example.com".to_string(),
+            salary: Money::new(-1_000, "eur", SalaryPeriod::Monthly),
+            end_date: date("2026-08-01"),
+            seats: 0,
+            ..request()
+        };
+        assert_eq!(
+            failing_fields(invalid.validate()),
+            vec![
+                "title",
+                "link",
+                "salary",
+                "salary.currency",
+                "seats",
+                "end_date"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skills_and_languages_are_checked() {
+        let invalid = CreateOfferRequest {
+            skills: vec!["Rust".to_string(), " ".to_string()],
+            languages: vec![
+                LanguageRequirement {
+                    language: "Deutsch".to_string(),
+                    level: CefrLevel::B1,
+                },
+                LanguageRequirement {
+                    language: "deutsch".to_string(),
+                    level: CefrLevel::C1,
+                },
+            ],
+            ..request()
+        };
+        assert_eq!(
+            failing_fields(invalid.validate()),
+            vec!["skills", "languages"]
+        );
+    }
+
+    #[test]
+    fn test_links_must_be_http_urls() {
+        for link in ["http://a.fr", "https://example.com:8080/x?y=1"] {
+            assert!(is_http_url(link), "{}", link);
+        }
+        for link in [
+            "ftp://a.fr",
+            "https://",
+            "https:///path",
+            "https://a b.fr",
+            "",
+        ] {
+            assert!(!is_http_url(link), "{}", link);
+        }
+    }
+
+    #[test]
+    fn test_update_dates_are_checked_against_existing_offer() {
+        let existing = Offer {
+            id: uuid::Uuid::new_v4(),
+            title: "Stage".to_string(),
+            link: "https://example.com".to_string(),
+            city: "Berlin".to_string(),
+            domain: "IT".to_string(),
+            salary: Money::new(140_000, "EUR", SalaryPeriod::Monthly),
+            base_salary: None,
+            start_date: date("2026-09-01"),
+            end_date: date("2027-02-28"),
+            available: true,
+            seats: 1,
+            reserved_seats: 0,
+            deleted_at: None,
+            application_deadline: None,
+            company_id: None,
+            description: None,
+            skills: vec![],
+            languages: vec![],
+            remote_policy: RemotePolicy::OnSite,
+            version: 1,
+        };
+        let update = UpdateOfferRequest {
+            title: None,
+            link: None,
+            city: None,
+            domain: None,
+            salary: None,
+            start_date: None,
+            end_date: Some(date("2026-08-01")),
+            available: None,
+            seats: None,
+            application_deadline: None,
+            company_id: None,
+            description: None,
+            skills: None,
+            languages: None,
+            remote_policy: None,
+        };
+        // Alone, a new end date cannot be compared with anything
+        assert!(update.validate().is_ok());
+        assert_eq!(
+            failing_fields(update.validate_against(&existing)),
+            vec!["end_date"]
+        );
+    }
+
+    #[test]
+    fn test_student_names_must_not_be_blank() {
+        let request = CreateStudentRequest {
+            firstname: "Alice".to_string(),
+            name: "".to_string(),
+            domain: "x".repeat(101),
+            email: None,
+            phone: None,
+            study_year: None,
+            languages: vec![],
+        };
+        assert_eq!(failing_fields(request.validate()), vec!["name", "domain"]);
+    }
+
+    #[test]
+    fn test_student_contact_details_are_checked() {
+        let request = CreateStudentRequest {
+            firstname: "Alice".to_string(),
+            name: "Dupont".to_string(),
+            domain: "IT".to_string(),
+            email: Some("alice.dupont@etu.univ-lyon1.fr".to_string()),
+            phone: Some("+33 6 12 34 56 78".to_string()),
+            study_year: Some(4),
+            languages: vec![],
+        };
+        assert!(request.validate().is_ok());
+
+        let request = CreateStudentRequest {
+            email: Some("alice@localhost".to_string()),
+            phone: Some("06-12-AB".to_string()),
+            study_year: Some(0),
+            ..request
+        };
+        assert_eq!(
+            failing_fields(request.validate()),
+            vec!["email", "phone", "study_year"]
+        );
+    }
+}
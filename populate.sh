@@ -1,14 +1,37 @@
 #!/bin/bash
 
-echo "Populating Students in Polytech (port 8080)"
+echo "Populating the domain catalog in Erasmumu (port 8081)"
+
+IT_ID=$(curl -s -X POST http://localhost:8081/domain \
+    -H "Content-Type: application/json" \
+    -d '{"name": "IT", "aliases": ["Informatique", "Computer Science"]}' \
+    | sed -n 's/.*"id":"\([^"]*\)".*/\1/p')
+
+curl -X POST http://localhost:8081/domain \
+    -H "Content-Type: application/json" \
+    -d "{\"name\": \"Data Science\", \"parent_id\": \"$IT_ID\"}"
+
+curl -X POST http://localhost:8081/domain \
+    -H "Content-Type: application/json" \
+    -d '{"name": "Business", "aliases": ["Commerce"]}'
+
+curl -X POST http://localhost:8081/domain \
+    -H "Content-Type: application/json" \
+    -d '{"name": "Engineering", "aliases": ["Ingénierie"]}'
+
+curl -X POST http://localhost:8081/domain \
+    -H "Content-Type: application/json" \
+    -d '{"name": "Life Science", "aliases": ["Sciences de la vie"]}'
+
+echo -e "\n\nPopulating Students in Polytech (port 8080)"
 
 curl -X POST http://localhost:8080/student \
     -H "Content-Type: application/json" \
-    -d '{"firstname": "Joris", "name": "Doe", "domain": "IT"}'
+    -d '{"firstname": "Joris", "name": "Doe", "domain": "IT", "email": "joris.doe@example.com", "study_year": 4, "languages": [{"language": "English", "level": "C1"}]}'
 
 curl -X POST http://localhost:8080/student \
     -H "Content-Type: application/json" \
-    -d '{"firstname": "Alice", "name": "Smith", "domain": "Business"}'
+    -d '{"firstname": "Alice", "name": "Smith", "domain": "Business", "email": "alice.smith@example.com", "study_year": 3}'
 
 echo -e "\n\nPopulating Offers in Erasmumu (port 8081)"
 
@@ -46,11 +69,11 @@ curl -X POST http://localhost:8081/offer \
 # London
 curl -X POST http://localhost:8081/offer \
     -H "Content-Type: application/json" \
-    -d '{"title": "AI Research Intern", "link": "https://example.com/ai", "city": "London", "domain": "IT", "salary": 2000.0, "start_date": "2026-08-01", "end_date": "2027-01-31", "available": true}'
+    -d '{"title": "AI Research Intern", "link": "https://example.com/ai", "city": "London", "domain": "IT", "salary": {"amount": 2040000, "currency": "GBP", "period": "yearly"}, "start_date": "2026-08-01", "end_date": "2027-01-31", "available": true}'
 
 curl -X POST http://localhost:8081/offer \
     -H "Content-Type: application/json" \
-    -d '{"title": "Biotech Researcher", "link": "https://example.com/bio", "city": "London", "domain": "Life Science", "salary": 1900.0, "start_date": "2026-08-01", "end_date": "2027-01-31", "available": true}'
+    -d '{"title": "Biotech Researcher", "link": "https://example.com/bio", "city": "London", "domain": "Life Science", "salary": {"amount": 160000, "currency": "GBP", "period": "monthly"}, "start_date": "2026-08-01", "end_date": "2027-01-31", "available": true}'
 
 echo -e "\n\nPopulating News in MI8 via Colporteur..."
 cargo run -p colporteur